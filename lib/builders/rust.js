@@ -1,8 +1,169 @@
 const keywords = require('rust-keywords')
 const { BaseBuilder, ARRAY_OF_LITERAL } = require('./base')
-/*:: const { Union, Interface, Method, Field } = require('../store')*/
+const { Interface, Union } = require('../store')
+/*:: const { Method, Field } = require('../store')*/
 
 
+/**
+ * Hand-written modules of the crate live next to the generated lib.rs
+ * and must be declared before the generated types
+ */
+const PRELUDE = `#[macro_use]
+extern crate serde_derive;
+extern crate serde;
+extern crate serde_json;
+
+#[cfg(feature = "multipart")]
+mod attach;
+mod callback_data;
+mod chat;
+mod chat_member;
+mod id;
+mod inline_keyboard_button;
+mod input_file;
+mod keyboard;
+mod message;
+mod message_entity;
+mod method;
+#[cfg(feature = "multipart")]
+mod multipart;
+mod parse_mode;
+mod recipient;
+mod reply_markup;
+mod response;
+mod unions;
+mod update;
+
+#[cfg(feature = "multipart")]
+pub use attach::attach_media;
+pub use callback_data::{
+    from_callback_data, from_callback_data_with, to_callback_data, to_callback_data_with,
+    CallbackDataError, CallbackStore, MemoryStore, CALLBACK_DATA_LIMIT,
+};
+pub use chat::ChatType;
+pub use id::{
+    CallbackQueryId, ChatId, FileId, FileUniqueId, InlineMessageId, InlineQueryId,
+    MessageIdentifier, PreCheckoutQueryId, ShippingQueryId, UserId,
+};
+pub use inline_keyboard_button::InlineKeyboardButtonAction;
+pub use input_file::InputFile;
+pub use keyboard::{
+    InlineKeyboardBuilder, KeyboardError, ReplyKeyboardBuilder, INLINE_BUTTON_LIMIT,
+    INLINE_ROW_LIMIT, REPLY_BUTTON_LIMIT, REPLY_ROW_LIMIT,
+};
+pub use message::MessageKind;
+pub use message_entity::MessageEntityKind;
+pub use method::{Close, GetMe, GetWebhookInfo, LogOut, MessageOrBool, Method};
+#[cfg(feature = "multipart")]
+pub use multipart::{EncodeError, Multipart, Part, RequestBody};
+pub use parse_mode::ParseMode;
+pub use recipient::{InvalidUsername, Recipient};
+pub use reply_markup::ReplyMarkup;
+pub use response::{ApiError, ApiErrorKind, ApiResponse, ResponseError};
+pub use update::UpdateKind;
+
+/// Unknown fields of an object, see the \`extra-fields\` feature
+#[cfg(feature = "extra-fields")]
+pub type Extra = std::collections::BTreeMap<String, serde_json::Value>;
+`
+
+/**
+ * Identifiers get newtypes of their own, see id.rs. The `id` field is
+ * typed by the object it belongs to, other fields by their name.
+ */
+const OWN_IDS = {
+  Chat: 'ChatId',
+  User: 'UserId',
+  CallbackQuery: 'CallbackQueryId',
+  InlineQuery: 'InlineQueryId',
+  ShippingQuery: 'ShippingQueryId',
+  PreCheckoutQuery: 'PreCheckoutQueryId',
+}
+const ID_FIELDS = [
+  [/^(\w+_)?file_id$/, 'FileId'],
+  [/^(\w+_)?file_unique_id$/, 'FileUniqueId'],
+  [/^user_id$/, 'UserId'],
+  [/^(reply_to_|forward_from_)?message_id$/, 'MessageIdentifier'],
+  [/^inline_message_id$/, 'InlineMessageId'],
+  [/^(\w+_)?chat_id$/, 'ChatId'],
+  [/^callback_query_id$/, 'CallbackQueryId'],
+  [/^inline_query_id$/, 'InlineQueryId'],
+  [/^shipping_query_id$/, 'ShippingQueryId'],
+  [/^pre_checkout_query_id$/, 'PreCheckoutQueryId'],
+]
+
+/**
+ * Telegram tells the variants of a union apart by one of their fields. The
+ * field is dropped from the variant structs and its value, given by the
+ * docs, names the variant on the wire.
+ */
+const UNION_TAGS = {
+  InputMedia: 'type',
+  InlineQueryResult: 'type',
+  PassportElementError: 'source',
+  ChatMember: 'status',
+  BotCommandScope: 'type',
+}
+/**
+ * Unions (de)serialized by hand in unions.rs, their variants can't be told
+ * apart by a tag alone
+ */
+const HAND_WRITTEN_UNIONS = ['InputMessageContent', 'InlineQueryResult']
+
+/**
+ * Fields typed by their name, whatever object they belong to
+ */
+const NAMED_FIELDS = [
+  [/^(\w+_)?parse_mode$/, 'ParseMode'],
+]
+
+/**
+ * Fields with a hand-written type, keyed by `Object.field`. The fields the
+ * type carries itself are dropped. `description` replaces the one of the
+ * docs, `optional` overrides theirs.
+ */
+const FIELD_TYPES = {
+  'Chat.type': { type: 'ChatType' },
+  // A file_id, an HTTP URL or “attach://<file_attach_name>”, see attach.rs
+  'InputMediaPhoto.media': { type: 'Box<InputFile>' },
+  'InputMediaVideo.media': { type: 'Box<InputFile>' },
+  'InputMediaAnimation.media': { type: 'Box<InputFile>' },
+  'InputMediaAudio.media': { type: 'Box<InputFile>' },
+  'InputMediaDocument.media': { type: 'Box<InputFile>' },
+  'MessageEntity.type': {
+    name: 'kind',
+    type: 'MessageEntityKind',
+    flatten: true,
+    note: 'Carries the url of a text_link, the user of a text_mention and the language of a pre entity.',
+    carries: ['url', 'user', 'language'],
+  },
+  'InlineKeyboardButton.url': {
+    name: 'action',
+    type: 'InlineKeyboardButtonAction',
+    flatten: true,
+    optional: false,
+    description: 'What happens when the button is pressed',
+    carries: [
+      'login_url',
+      'callback_data',
+      'switch_inline_query',
+      'switch_inline_query_current_chat',
+      'callback_game',
+      'pay',
+    ],
+  },
+}
+/**
+ * Builtins written by hand in a module of their own, e.g. InputFile in
+ * input_file.rs
+ */
+const HAND_WRITTEN_TYPES = ['InputFile']
+
+/**
+ * Objects deserialized by hand next to the type of one of their fields
+ */
+const HAND_WRITTEN_DESERIALIZE = ['MessageEntity', 'InlineKeyboardButton']
+
 // const hasLifetime = (typeName) => !(['i64', 'bool', 'f64'].includes(typeName))
 
 class RustBuilder extends BaseBuilder {
@@ -36,52 +197,210 @@ class RustBuilder extends BaseBuilder {
   }
 
   buildInterface(object/*: Interface*/) {
+    if (HAND_WRITTEN_TYPES.includes(object.name)) {
+      return null
+    }
+
+    const tag = this.findVariantTag(object.name)
+    const carried = Object.keys(object.fields)
+      .map((fieldName) => FIELD_TYPES[`${object.name}.${fieldName}`])
+      .reduce((acc, field) => acc.concat((field && field.carries) || []), [])
     const fields = Object.keys(object.fields)
-      .map((fieldName) => this.buildField(object.fields[fieldName]))
+      .filter((fieldName) => fieldName !== tag && !carried.includes(fieldName))
+      .map((fieldName) => this.buildField(object.fields[fieldName], object.name))
+      .concat(this.buildExtraField())
     // Disabled because not used now, but can be used in future
     // const hasLifetimeIn = fields.some((def) => def.indexOf('\'a') !== -1)
     const lifetime = '' // !hasLifetimeIn ? '<\'a> ' : ''
 
+    const derive = HAND_WRITTEN_DESERIALIZE.includes(object.name)
+      ? 'Serialize, Debug, Clone'
+      : 'Serialize, Deserialize, Debug, Clone'
+
     return `${this.buildComments(object.description || '', object.links).split('\n').map((e) => e.trim()).join('\n')}
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(${derive})]
 pub struct ${object.name}${lifetime} {
   ${fields.join('\n\n  ')}
 }`
   }
 
-  // TODO: Implement method serialization for Rust
-  buildMethod(object/*: Method*/) { // eslint-disable-line no-unused-vars
-    return ''
+  buildExtraField() {
+    return `/// Fields unknown to this version of the crate, written back on
+  /// serialization
+  #[cfg(feature = "extra-fields")]
+  #[serde(flatten)]
+  pub extra: Extra,`
   }
 
-  buildUnion(object/*: Union*/) {
+  buildMethod(object/*: Method*/) {
+    const fields = Object.keys(object.fields)
+      .map((fieldName) => this.buildField(object.fields[fieldName], object.name))
+    const methodName = `${object.name[0].toLowerCase()}${object.name.slice(1)}`
+
     return `${this.buildComments(object.description || '', object.links).split('\n').map((e) => e.trim()).join('\n')}
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ${object.name} {
+  ${fields.join('\n\n  ')}
+}
+
+impl Method for ${object.name} {
+  const NAME: &'static str = "${methodName}";
+  type Response = ${this.buildResponse(object.description || '')};
+}`
+  }
+
+  /**
+   * The docs only describe the result of a method in prose, e.g.
+   * "On success, the sent Message is returned"
+   */
+  buildResponse(description/*: string*/) {
+    if (description.includes('otherwise True')) {
+      return 'MessageOrBool'
+    }
+
+    const array = /[Aa]rray of (\w+)/.exec(description)
+
+    if (array) {
+      const name = this.store.get(array[1]) ? array[1] : array[1].replace(/s$/, '')
+
+      return `Vec<${name}>`
+    }
+
+    const result = description.slice(description.search(/Returns|On success|is returned/))
+    const natives = { True: 'bool', Int: 'i64', String: 'String' }
+    const word = (result.match(/\w+/g) || [])
+      .find((e) => natives[e] || this.store.get(e) instanceof Interface || this.store.get(e) instanceof Union)
+
+    if (!word) {
+      throw new Error(`Unknown result of the method: ${description}`)
+    }
+
+    return natives[word] || word
+  }
+
+  buildUnion(object/*: Union*/) {
+    const tag = UNION_TAGS[object.name]
+    const handWritten = HAND_WRITTEN_UNIONS.includes(object.name)
+    const attributes = handWritten
+      ? ['#[derive(Debug, Clone)]']
+      : ['#[derive(Serialize, Deserialize, Debug, Clone)]'].concat(tag ? `#[serde(tag = "${tag}")]` : [])
+    const variants = object.variants.map((vari) => {
+      const rename = tag && !handWritten
+        ? `#[serde(rename = "${this.buildTagValue(vari, tag)}")]\n  `
+        : ''
+
+      return `${rename}${vari}(Box<${vari}>)`
+    })
+
+    return `${this.buildComments(object.description || '', object.links).split('\n').map((e) => e.trim()).join('\n')}
+${attributes.join('\n')}
 pub enum ${object.name} {
-  ${object.variants.map((vari) => `${vari}(Box<${vari}>)`).join(',\n  ')}
+  ${variants.join(',\n  ')},
 }`
   }
 
-  buildUnionOfTypes(types/*: Array<string>*/) { // eslint-disable-line no-unused-vars
-    throw new Error('Unions are not yet supported for Rust')
+  /**
+   * The tag of the union the object is a variant of, if any
+   */
+  findVariantTag(name/*: string*/)/*: ?string*/ {
+    const union = [...this.store.iterate()]
+      .find((type) => type instanceof Union && type.variants.includes(name))
+
+    return union ? UNION_TAGS[union.name] : null
+  }
+
+  /**
+   * The docs describe the tag as e.g. "Type of the result, must be photo" or
+   * "The member's status in the chat, always “creator”"
+   */
+  buildTagValue(variant/*: string*/, tag/*: string*/) {
+    const type = this.store.get(variant)
+    const field = type instanceof Interface ? type.fields[tag] : null
+    const value = field && /(?:must be|always) “?(\w+)/.exec(field.description || '')
+
+    if (!value) {
+      throw new Error(`No value of the \`${tag}\` tag of ${variant}`)
+    }
+
+    return value[1]
+  }
+
+  /**
+   * Only the unions the docs use are supported: "Integer or String" is a
+   * chat_id, "InputFile or String" is a file_id, a URL or an upload, and the
+   * four keyboard objects are the reply_markup of the send* methods
+   */
+  buildUnionOfTypes(types/*: Array<string>*/) {
+    const key = types.slice().sort().join(' or ')
+
+    if (key === 'Integer or String') {
+      return 'Recipient'
+    }
+    if (key === 'InputFile or String') {
+      return this.buildReference('InputFile')
+    }
+    if (key === 'ForceReply or InlineKeyboardMarkup or ReplyKeyboardMarkup or ReplyKeyboardRemove') {
+      return this.buildReference('ReplyMarkup')
+    }
+
+    throw new Error(`Unions of ${types.join(', ')} are not supported for Rust`)
+  }
+
+  /**
+   * Hand-written types first, then the ones picked by the name of the
+   * field, then identifiers, see id.rs
+   */
+  buildFieldType(object/*: Field*/, owner/*: string*/) {
+    const own = FIELD_TYPES[`${owner}.${object.name}`]
+
+    if (own) {
+      return own.type
+    }
+
+    const named = NAMED_FIELDS.find(([pattern]) => pattern.test(object.name))
+
+    if (named) {
+      return named[1]
+    }
+
+    const type = this.buildNativeType(object.type)
+
+    if (type !== 'i64' && type !== 'String') {
+      return type
+    }
+    if (object.name === 'id') {
+      return OWN_IDS[owner] || type
+    }
+
+    const id = ID_FIELDS.find(([pattern]) => pattern.test(object.name))
+
+    return id ? id[1] : type
   }
 
-  buildField(object/*: Field*/) {
-    const type = object.optional
-      ? `Option<${this.buildNativeType(object.type)}>`
-      : this.buildNativeType(object.type)
-    const name = keywords.indexOf(object.name) !== -1
+  buildField(object/*: Field*/, owner/*: string*/) {
+    const own = FIELD_TYPES[`${owner}.${object.name}`] || {}
+    const optional = own.optional === undefined ? object.optional : own.optional
+    const type = optional
+      ? `Option<${this.buildFieldType(object, owner)}>`
+      : this.buildFieldType(object, owner)
+    const name = own.name || (keywords.indexOf(object.name) !== -1
       ? `${object.name}_tl`
-      : object.name
-    const rename = name !== object.name
+      : object.name)
+    const rename = name !== object.name && !own.flatten
       ? `#[serde(rename = "${object.name}")]`
       : ''
-    const comments = this.buildComments(object.description || '', object.links)
+    const flatten = own.flatten ? '#[serde(flatten)]' : ''
+    const skip = optional
+      ? '#[serde(skip_serializing_if = "Option::is_none")]'
+      : ''
+    const attributes = [rename, flatten, skip].filter((attr) => !!attr)
+      .map((attr) => `\n  ${attr}`).join('')
+    const description = own.description
+      || [object.description, own.note].filter((e) => !!e).join(' ')
+    const comments = this.buildComments(description, own.description ? [] : object.links)
     const lifetime = '' // hasLifetime(this.buildNativeType(object.type)) ? '&\'a ' : ''
 
-    return rename
-      ? `${comments}\n  ${rename}\n  pub ${name}: ${lifetime}${type},`
-      : `${comments}\n  pub ${name}: ${lifetime}${type},`
+    return `${comments}${attributes}\n  pub ${name}: ${lifetime}${type},`
   }
 
   buildModule(types/*: string[]*/) {
@@ -90,10 +409,7 @@ pub enum ${object.name} {
 
   buildProgram(body/*: any*/) {
     return {
-      code: `#[macro_use]
-extern crate serde_derive;
-extern crate serde;
-
+      code: `${PRELUDE}
 ${body}
 `,
     }
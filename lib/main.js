@@ -145,7 +145,7 @@ async function main() {
 
   buildFlow(store)
   buildTypeScript(store)
-  // buildRust(store)
+  buildRust(store)
 }
 
 module.exports = main
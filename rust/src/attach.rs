@@ -0,0 +1,87 @@
+use std::iter;
+use std::mem;
+
+use multipart::Part;
+use {InputFile, InputMedia};
+
+/// Moves the new files of a media list into parts of their own.
+///
+/// `media` and `thumb` fields that are uploads are replaced with
+/// `attach://<name>` references, the returned parts carry the files under
+/// those names. Names already referred to by the media are not reused. The
+/// same file uploaded twice is sent once. Pass the parts to
+/// `RequestBody::with_attachments` along with the sendMediaGroup or
+/// editMessageMedia payload.
+pub fn attach_media(media: &mut [Box<InputMedia>]) -> Vec<Part> {
+    // Attachments the caller named already keep their names
+    let mut taken = Vec::new();
+    for item in media.iter_mut() {
+        let (media, thumb) = files(item);
+
+        for file in iter::once(media).chain(thumb) {
+            if let InputFile::Attach(ref name) = **file {
+                taken.push(name.clone());
+            }
+        }
+    }
+
+    let mut parts = Vec::new();
+    for item in media {
+        let (media, thumb) = files(item);
+
+        attach(media, &mut parts, &taken);
+        if let Some(thumb) = thumb {
+            attach(thumb, &mut parts, &taken);
+        }
+    }
+
+    parts
+}
+
+fn files(item: &mut InputMedia) -> (&mut Box<InputFile>, Option<&mut Box<InputFile>>) {
+    match *item {
+        InputMedia::InputMediaPhoto(ref mut m) => (&mut m.media, None),
+        InputMedia::InputMediaVideo(ref mut m) => (&mut m.media, m.thumb.as_mut()),
+        InputMedia::InputMediaAudio(ref mut m) => (&mut m.media, m.thumb.as_mut()),
+        InputMedia::InputMediaDocument(ref mut m) => (&mut m.media, m.thumb.as_mut()),
+        InputMedia::InputMediaAnimation(ref mut m) => (&mut m.media, m.thumb.as_mut()),
+    }
+}
+
+fn attach(file: &mut InputFile, parts: &mut Vec<Part>, taken: &[String]) {
+    let mut part = match mem::replace(file, InputFile::Attach(String::new())) {
+        InputFile::Upload {
+            filename,
+            data,
+            mime_type,
+        } => Part::file(String::new(), filename, data, mime_type),
+        other => {
+            *file = other;
+            return;
+        }
+    };
+
+    let name = match parts.iter().find(|p| same_file(p, &part)) {
+        Some(existing) => existing.name.clone(),
+        None => {
+            part.name = unused_name(parts, taken);
+            let name = part.name.clone();
+            parts.push(part);
+            name
+        }
+    };
+
+    *file = InputFile::Attach(name);
+}
+
+/// The first `file<n>` that is neither a part yet nor taken by the caller
+fn unused_name(parts: &[Part], taken: &[String]) -> String {
+    (0..)
+        .map(|n| format!("file{}", n))
+        .find(|name| !taken.contains(name) && parts.iter().all(|p| p.name != *name))
+        .expect("unbounded range")
+}
+
+fn same_file(a: &Part, b: &Part) -> bool {
+    a.filename == b.filename && a.mime_type == b.mime_type && a.data == b.data
+}
@@ -0,0 +1,844 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+use serde::Deserialize;
+
+use CallbackQuery;
+
+/// Most bytes of `callback_data` Telegram accepts
+pub const CALLBACK_DATA_LIMIT: usize = 64;
+
+/// Separates the values in callback data
+const SEPARATOR: char = ':';
+
+/// Written for `None`
+const NONE: &str = "~";
+
+/// Starts the key of callback data kept in a `CallbackStore`
+const STORED: char = '#';
+
+/// Packs a value into callback data, e.g. `like:42` for
+/// `Action::Like(42)` of a `#[derive(Serialize)] enum Action`.
+///
+/// Values are written one after another, separated by colons: structs and
+/// tuples by their fields, enum variants by the name and the fields, `None`
+/// as `~`, sequences and maps by the length and the items. Field names are
+/// not written, so the type must stay the same between encoding and
+/// decoding, and `skip_serializing_if` can't be used.
+///
+/// Fails if the result is empty or longer than 64 bytes.
+pub fn to_callback_data<T: Serialize + ?Sized>(value: &T) -> Result<String, CallbackDataError> {
+    let data = encode(value)?;
+
+    check_length(&data)?;
+    Ok(data)
+}
+
+/// Reads callback data written by `to_callback_data`
+pub fn from_callback_data<'de, T: Deserialize<'de>>(
+    data: &'de str,
+) -> Result<T, CallbackDataError> {
+    let mut decoder = Decoder::new(data);
+    let value = T::deserialize(&mut decoder)?;
+
+    if decoder.next < decoder.tokens.len() {
+        return Err(CallbackDataError::Invalid(format!(
+            "trailing data after {} values",
+            decoder.next
+        )));
+    }
+
+    Ok(value)
+}
+
+/// Like `to_callback_data`, but data longer than 64 bytes is moved to the
+/// store and only its key is sent to Telegram
+pub fn to_callback_data_with<T, S>(value: &T, store: &mut S) -> Result<String, CallbackDataError>
+where
+    T: Serialize + ?Sized,
+    S: CallbackStore + ?Sized,
+{
+    let data = encode(value)?;
+
+    if data.len() <= CALLBACK_DATA_LIMIT {
+        check_length(&data)?;
+        return Ok(data);
+    }
+
+    let data = format!("{}{}", STORED, store.insert(data));
+
+    check_length(&data)?;
+    Ok(data)
+}
+
+/// Reads callback data written by `to_callback_data_with`, looking the
+/// stored data up by its key
+pub fn from_callback_data_with<T, S>(data: &str, store: &S) -> Result<T, CallbackDataError>
+where
+    T: DeserializeOwned,
+    S: CallbackStore + ?Sized,
+{
+    match data.strip_prefix(STORED) {
+        Some(key) => match store.get(key) {
+            Some(stored) => from_callback_data(&stored),
+            None => Err(CallbackDataError::UnknownKey(key.to_owned())),
+        },
+        None => from_callback_data(data),
+    }
+}
+
+fn encode<T: Serialize + ?Sized>(value: &T) -> Result<String, CallbackDataError> {
+    let mut encoder = Encoder {
+        data: String::new(),
+        empty: true,
+    };
+
+    value.serialize(&mut encoder)?;
+    Ok(encoder.data)
+}
+
+fn check_length(data: &str) -> Result<(), CallbackDataError> {
+    if data.is_empty() {
+        Err(CallbackDataError::Empty)
+    } else if data.len() > CALLBACK_DATA_LIMIT {
+        Err(CallbackDataError::TooLong { length: data.len() })
+    } else {
+        Ok(())
+    }
+}
+
+impl CallbackQuery {
+    /// The callback data of the pressed button, read with
+    /// `from_callback_data`
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, CallbackDataError> {
+        match self.data {
+            Some(ref data) => from_callback_data(data),
+            None => Err(CallbackDataError::NoData),
+        }
+    }
+}
+
+/// Keeps callback data that doesn't fit into a button, see
+/// `to_callback_data_with`
+pub trait CallbackStore {
+    /// Keeps the data and returns a key to get it back, the key must be at
+    /// most 63 bytes long
+    fn insert(&mut self, data: String) -> String;
+
+    /// Data kept under the key, if it's still there
+    fn get(&self, key: &str) -> Option<String>;
+}
+
+/// A `CallbackStore` in memory, the data is lost when it's dropped
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    data: HashMap<String, String>,
+    keys: HashMap<String, String>,
+}
+
+impl MemoryStore {
+    /// An empty store
+    pub fn new() -> Self {
+        MemoryStore::default()
+    }
+}
+
+impl CallbackStore for MemoryStore {
+    fn insert(&mut self, data: String) -> String {
+        if let Some(key) = self.keys.get(&data) {
+            return key.clone();
+        }
+
+        let key = format!("{:x}", self.data.len());
+        self.data.insert(key.clone(), data.clone());
+        self.keys.insert(data, key.clone());
+        key
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        self.data.get(key).cloned()
+    }
+}
+
+/// Callback data that can't be written or read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackDataError {
+    /// Callback data must be at least one byte long
+    Empty,
+    /// The encoded value is longer than `CALLBACK_DATA_LIMIT` bytes
+    TooLong { length: usize },
+    /// The store has nothing under this key
+    UnknownKey(String),
+    /// The callback query has no data, e.g. it comes from a game
+    NoData,
+    /// The value can't be written as or read from callback data
+    Invalid(String),
+}
+
+impl fmt::Display for CallbackDataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CallbackDataError::Empty => f.write_str("callback data is empty"),
+            CallbackDataError::TooLong { length } => write!(
+                f,
+                "callback data is {} bytes long, at most {} are allowed",
+                length, CALLBACK_DATA_LIMIT
+            ),
+            CallbackDataError::UnknownKey(ref key) => {
+                write!(f, "no callback data stored under {:?}", key)
+            }
+            CallbackDataError::NoData => f.write_str("callback query has no data"),
+            CallbackDataError::Invalid(ref message) => f.write_str(message),
+        }
+    }
+}
+
+impl error::Error for CallbackDataError {}
+
+impl ser::Error for CallbackDataError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CallbackDataError::Invalid(msg.to_string())
+    }
+}
+
+impl de::Error for CallbackDataError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CallbackDataError::Invalid(msg.to_string())
+    }
+}
+
+/// Writes the values of callback data
+struct Encoder {
+    data: String,
+    empty: bool,
+}
+
+impl Encoder {
+    fn token(&mut self, token: &str) {
+        if !self.empty {
+            self.data.push(SEPARATOR);
+        }
+        self.data.push_str(token);
+        self.empty = false;
+    }
+
+    /// Escapes the separator, the backslash, and a leading `~` or `#` so the
+    /// string can't be taken for `None` or a stored key
+    fn string(&mut self, value: &str) {
+        let mut token = String::with_capacity(value.len());
+
+        if value.starts_with(NONE) || value.starts_with(STORED) {
+            token.push('\\');
+        }
+        for c in value.chars() {
+            if c == SEPARATOR || c == '\\' {
+                token.push('\\');
+            }
+            token.push(c);
+        }
+
+        self.token(&token);
+    }
+}
+
+macro_rules! encode_display {
+    ($($method:ident: $ty:ty,)*) => {
+        $(
+            fn $method(self, value: $ty) -> Result<(), CallbackDataError> {
+                self.token(&value.to_string());
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for &mut Encoder {
+    type Ok = ();
+    type Error = CallbackDataError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    encode_display! {
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64,
+    }
+
+    fn serialize_bool(self, value: bool) -> Result<(), CallbackDataError> {
+        self.token(if value { "1" } else { "0" });
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<(), CallbackDataError> {
+        self.string(value.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), CallbackDataError> {
+        self.string(value);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<(), CallbackDataError> {
+        Err(ser::Error::custom(
+            "bytes can't be written as callback data",
+        ))
+    }
+
+    fn serialize_none(self) -> Result<(), CallbackDataError> {
+        self.token(NONE);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), CallbackDataError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CallbackDataError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), CallbackDataError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), CallbackDataError> {
+        self.string(variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), CallbackDataError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), CallbackDataError> {
+        self.string(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self, CallbackDataError> {
+        let len = len.ok_or_else(|| ser::Error::custom("sequences must have a known length"))?;
+
+        self.token(&len.to_string());
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self, CallbackDataError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self, CallbackDataError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self, CallbackDataError> {
+        self.string(variant);
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self, CallbackDataError> {
+        self.serialize_seq(len)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, CallbackDataError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self, CallbackDataError> {
+        self.string(variant);
+        Ok(self)
+    }
+}
+
+macro_rules! encode_items {
+    ($($trait:ident::$method:ident,)*) => {
+        $(
+            impl ser::$trait for &mut Encoder {
+                type Ok = ();
+                type Error = CallbackDataError;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CallbackDataError> {
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> Result<(), CallbackDataError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+encode_items! {
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+}
+
+impl ser::SerializeMap for &mut Encoder {
+    type Ok = ();
+    type Error = CallbackDataError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), CallbackDataError> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), CallbackDataError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), CallbackDataError> {
+        Ok(())
+    }
+}
+
+macro_rules! encode_fields {
+    ($($trait:ident,)*) => {
+        $(
+            impl ser::$trait for &mut Encoder {
+                type Ok = ();
+                type Error = CallbackDataError;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    _key: &'static str,
+                    value: &T,
+                ) -> Result<(), CallbackDataError> {
+                    value.serialize(&mut **self)
+                }
+
+                fn skip_field(&mut self, key: &'static str) -> Result<(), CallbackDataError> {
+                    Err(ser::Error::custom(format_args!(
+                        "field `{}` can't be skipped in callback data",
+                        key
+                    )))
+                }
+
+                fn end(self) -> Result<(), CallbackDataError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+encode_fields! {
+    SerializeStruct,
+    SerializeStructVariant,
+}
+
+/// Reads the values of callback data
+struct Decoder<'de> {
+    tokens: Vec<&'de str>,
+    next: usize,
+}
+
+impl<'de> Decoder<'de> {
+    /// Splits the data at separators that aren't escaped
+    fn new(data: &'de str) -> Self {
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        let mut escaped = false;
+
+        for (index, c) in data.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == SEPARATOR {
+                tokens.push(&data[start..index]);
+                start = index + 1;
+            }
+        }
+        if !data.is_empty() {
+            tokens.push(&data[start..]);
+        }
+
+        Decoder { tokens, next: 0 }
+    }
+
+    fn peek(&self) -> Option<&'de str> {
+        self.tokens.get(self.next).cloned()
+    }
+
+    fn token(&mut self) -> Result<&'de str, CallbackDataError> {
+        let token = self
+            .peek()
+            .ok_or_else(|| de::Error::custom("callback data ended too early"))?;
+
+        self.next += 1;
+        Ok(token)
+    }
+
+    fn parse<T: ::std::str::FromStr>(&mut self, expected: &str) -> Result<T, CallbackDataError> {
+        let token = self.token()?;
+
+        token
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(token), &expected))
+    }
+
+    fn string(&mut self) -> Result<String, CallbackDataError> {
+        let token = self.token()?;
+        let mut value = String::with_capacity(token.len());
+        let mut escaped = false;
+
+        for c in token.chars() {
+            if c == '\\' && !escaped {
+                escaped = true;
+            } else {
+                value.push(c);
+                escaped = false;
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn len(&mut self) -> Result<usize, CallbackDataError> {
+        self.parse("a length")
+    }
+}
+
+macro_rules! decode_parsed {
+    ($($method:ident => $visit:ident: $expected:expr,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+                visitor.$visit(self.parse($expected)?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Decoder<'de> {
+    type Error = CallbackDataError;
+
+    decode_parsed! {
+        deserialize_i8 => visit_i8: "an integer",
+        deserialize_i16 => visit_i16: "an integer",
+        deserialize_i32 => visit_i32: "an integer",
+        deserialize_i64 => visit_i64: "an integer",
+        deserialize_u8 => visit_u8: "an integer",
+        deserialize_u16 => visit_u16: "an integer",
+        deserialize_u32 => visit_u32: "an integer",
+        deserialize_u64 => visit_u64: "an integer",
+        deserialize_f32 => visit_f32: "a number",
+        deserialize_f64 => visit_f64: "a number",
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, CallbackDataError> {
+        Err(de::Error::custom(
+            "callback data doesn't describe itself, the type must be known",
+        ))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+        match self.token()? {
+            "1" => visitor.visit_bool(true),
+            "0" => visitor.visit_bool(false),
+            other => Err(de::Error::invalid_value(
+                de::Unexpected::Str(other),
+                &"1 or 0",
+            )),
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+        let value = self.string()?;
+        let mut chars = value.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&value),
+                &"a character",
+            )),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+        match self.peek() {
+            Some(token) if !token.contains('\\') => {
+                self.next += 1;
+                visitor.visit_borrowed_str(token)
+            }
+            _ => visitor.visit_string(self.string()?),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(
+        self,
+        _visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        Err(de::Error::custom("bytes can't be read from callback data"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        if self.peek() == Some(NONE) {
+            self.next += 1;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+        let len = self.len()?;
+
+        visitor.visit_seq(Items {
+            decoder: self,
+            left: len,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        visitor.visit_seq(Items {
+            decoder: self,
+            left: len,
+        })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CallbackDataError> {
+        let len = self.len()?;
+
+        visitor.visit_map(Items {
+            decoder: self,
+            left: len,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        self.deserialize_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+}
+
+/// Items of a sequence, a tuple or a struct, or entries of a map
+struct Items<'a, 'de: 'a> {
+    decoder: &'a mut Decoder<'de>,
+    left: usize,
+}
+
+impl<'a, 'de> de::SeqAccess<'de> for Items<'a, 'de> {
+    type Error = CallbackDataError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, CallbackDataError> {
+        if self.left == 0 {
+            return Ok(None);
+        }
+
+        self.left -= 1;
+        seed.deserialize(&mut *self.decoder).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.left)
+    }
+}
+
+impl<'a, 'de> de::MapAccess<'de> for Items<'a, 'de> {
+    type Error = CallbackDataError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, CallbackDataError> {
+        if self.left == 0 {
+            return Ok(None);
+        }
+
+        self.left -= 1;
+        seed.deserialize(&mut *self.decoder).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        seed.deserialize(&mut *self.decoder)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.left)
+    }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Decoder<'de> {
+    type Error = CallbackDataError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self), CallbackDataError> {
+        let variant = self.string()?;
+        let value = seed.deserialize(variant.into_deserializer())?;
+
+        Ok((value, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Decoder<'de> {
+    type Error = CallbackDataError;
+
+    fn unit_variant(self) -> Result<(), CallbackDataError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, CallbackDataError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CallbackDataError> {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
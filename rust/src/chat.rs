@@ -0,0 +1,88 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use Chat;
+
+/// Type of chat, can be either “private”, “group”, “supergroup” or “channel”.
+/// Types added to the Bot API later are kept in `Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChatType {
+    Private,
+    Group,
+    Supergroup,
+    Channel,
+    Unknown(String),
+}
+
+impl ChatType {
+    /// Value of the `type` field as sent by Telegram
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChatType::Private => "private",
+            ChatType::Group => "group",
+            ChatType::Supergroup => "supergroup",
+            ChatType::Channel => "channel",
+            ChatType::Unknown(ref other) => other,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChatType {
+    fn from(value: &'a str) -> ChatType {
+        match value {
+            "private" => ChatType::Private,
+            "group" => ChatType::Group,
+            "supergroup" => ChatType::Supergroup,
+            "channel" => ChatType::Channel,
+            other => ChatType::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ChatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ChatType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChatType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(ChatType::from(value.as_str()))
+    }
+}
+
+impl Chat {
+    /// True, if this is a private chat with a user
+    pub fn is_private(&self) -> bool {
+        self.type_tl == ChatType::Private
+    }
+
+    /// True, if this is a basic group
+    pub fn is_group(&self) -> bool {
+        self.type_tl == ChatType::Group
+    }
+
+    /// True, if this is a supergroup
+    pub fn is_supergroup(&self) -> bool {
+        self.type_tl == ChatType::Supergroup
+    }
+
+    /// True, if this is either a group or a supergroup
+    pub fn is_group_like(&self) -> bool {
+        self.is_group() || self.is_supergroup()
+    }
+
+    /// True, if this is a channel
+    pub fn is_channel(&self) -> bool {
+        self.type_tl == ChatType::Channel
+    }
+}
@@ -0,0 +1,58 @@
+use {ChatMember, User};
+
+impl ChatMember {
+    /// Information about the user, whatever the member's status is
+    pub fn user(&self) -> &User {
+        match *self {
+            ChatMember::ChatMemberOwner(ref m) => &m.user,
+            ChatMember::ChatMemberAdministrator(ref m) => &m.user,
+            ChatMember::ChatMemberMember(ref m) => &m.user,
+            ChatMember::ChatMemberRestricted(ref m) => &m.user,
+            ChatMember::ChatMemberLeft(ref m) => &m.user,
+            ChatMember::ChatMemberBanned(ref m) => &m.user,
+        }
+    }
+
+    /// The member's status in the chat as sent by Telegram: “creator”,
+    /// “administrator”, “member”, “restricted”, “left” or “kicked”
+    pub fn status(&self) -> &'static str {
+        match *self {
+            ChatMember::ChatMemberOwner(_) => "creator",
+            ChatMember::ChatMemberAdministrator(_) => "administrator",
+            ChatMember::ChatMemberMember(_) => "member",
+            ChatMember::ChatMemberRestricted(_) => "restricted",
+            ChatMember::ChatMemberLeft(_) => "left",
+            ChatMember::ChatMemberBanned(_) => "kicked",
+        }
+    }
+
+    /// True, if the member is the owner or an administrator of the chat
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            *self,
+            ChatMember::ChatMemberOwner(_) | ChatMember::ChatMemberAdministrator(_)
+        )
+    }
+
+    /// True, if the user is a member of the chat at the moment of the request.
+    /// Restricted users may be outside of the chat
+    pub fn is_member(&self) -> bool {
+        match *self {
+            ChatMember::ChatMemberOwner(_)
+            | ChatMember::ChatMemberAdministrator(_)
+            | ChatMember::ChatMemberMember(_) => true,
+            ChatMember::ChatMemberRestricted(ref m) => m.is_member,
+            ChatMember::ChatMemberLeft(_) | ChatMember::ChatMemberBanned(_) => false,
+        }
+    }
+
+    /// Restricted and banned only. Date when restrictions will be lifted for
+    /// this user, unix time. `Some(0)` means forever
+    pub fn until_date(&self) -> Option<i64> {
+        match *self {
+            ChatMember::ChatMemberRestricted(ref m) => Some(m.until_date),
+            ChatMember::ChatMemberBanned(ref m) => Some(m.until_date),
+            _ => None,
+        }
+    }
+}
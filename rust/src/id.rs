@@ -0,0 +1,140 @@
+use std::fmt;
+
+use InputFile;
+
+macro_rules! integer_id {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[serde(transparent)]
+        pub struct $name(pub i64);
+
+        impl From<i64> for $name {
+            fn from(id: i64) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(id: $name) -> i64 {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+macro_rules! string_id {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            /// The identifier as sent by Telegram
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> $name {
+                $name(id)
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(id: &'a str) -> $name {
+                $name(id.to_owned())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+integer_id! {
+    /// Unique identifier of a chat. Private chats share the identifier of the
+    /// user.
+    ChatId
+}
+
+integer_id! {
+    /// Unique identifier of a user or a bot
+    UserId
+}
+
+integer_id! {
+    /// Unique identifier of a message inside its chat
+    MessageIdentifier
+}
+
+string_id! {
+    /// Identifier of a file, can be used to download or reuse the file
+    FileId
+}
+
+string_id! {
+    /// Identifier of a file that is the same over time and for different
+    /// bots. Can't be used to download or reuse the file.
+    FileUniqueId
+}
+
+string_id! {
+    /// Unique identifier of a callback query, see answerCallbackQuery
+    CallbackQueryId
+}
+
+string_id! {
+    /// Unique identifier of an inline query, see answerInlineQuery
+    InlineQueryId
+}
+
+string_id! {
+    /// Unique identifier of a shipping query, see answerShippingQuery
+    ShippingQueryId
+}
+
+string_id! {
+    /// Unique identifier of a pre-checkout query, see answerPreCheckoutQuery
+    PreCheckoutQueryId
+}
+
+string_id! {
+    /// Identifier of a message sent via the bot in inline mode
+    InlineMessageId
+}
+
+impl From<UserId> for ChatId {
+    fn from(id: UserId) -> ChatId {
+        ChatId(id.0)
+    }
+}
+
+impl From<FileId> for InputFile {
+    fn from(id: FileId) -> InputFile {
+        InputFile::FileId(id.0)
+    }
+}
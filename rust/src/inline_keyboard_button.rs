@@ -0,0 +1,207 @@
+use serde::de::Error;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{self, Map, Value};
+
+use message_entity::take;
+use {CallbackGame, InlineKeyboardButton, LoginUrl};
+
+/// What an inline keyboard button does when pressed. A button has exactly
+/// one action, written next to its `text` as one of the optional fields of
+/// the Bot API object.
+#[derive(Debug, Clone)]
+pub enum InlineKeyboardButtonAction {
+    /// `url`: HTTP or tg:// url to be opened
+    Url(String),
+    /// `login_url`: an HTTP URL used to automatically authorize the user
+    LoginUrl(Box<LoginUrl>),
+    /// `callback_data`: data to be sent in a callback query to the bot,
+    /// 1-64 bytes
+    Callback(String),
+    /// `switch_inline_query`: inline query to insert in a chat the user
+    /// selects
+    SwitchInline(String),
+    /// `switch_inline_query_current_chat`: inline query to insert in the
+    /// current chat
+    SwitchInlineCurrentChat(String),
+    /// `callback_game`: the game to launch, the button must be the first one
+    /// in the first row
+    CallbackGame(Box<CallbackGame>),
+    /// `pay`: a Pay button, must be the first one in the first row
+    Pay,
+    /// An action added to the Bot API later than this version of the crate
+    Unknown {
+        /// Name of the field
+        field: String,
+        /// Value of the field
+        value: Value,
+    },
+}
+
+impl InlineKeyboardButton {
+    /// A button with the given label and action
+    pub fn new<T: Into<String>>(text: T, action: InlineKeyboardButtonAction) -> Self {
+        InlineKeyboardButton {
+            text: text.into(),
+            action,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+
+    /// A button opening the URL
+    pub fn url<T: Into<String>, U: Into<String>>(text: T, url: U) -> Self {
+        Self::new(text, InlineKeyboardButtonAction::Url(url.into()))
+    }
+
+    /// A button sending the data in a callback query
+    pub fn callback<T: Into<String>, D: Into<String>>(text: T, data: D) -> Self {
+        Self::new(text, InlineKeyboardButtonAction::Callback(data.into()))
+    }
+
+    /// A button inserting the inline query in a chat the user selects
+    pub fn switch_inline<T: Into<String>, Q: Into<String>>(text: T, query: Q) -> Self {
+        Self::new(text, InlineKeyboardButtonAction::SwitchInline(query.into()))
+    }
+
+    /// A button inserting the inline query in the current chat
+    pub fn switch_inline_current_chat<T: Into<String>, Q: Into<String>>(text: T, query: Q) -> Self {
+        Self::new(
+            text,
+            InlineKeyboardButtonAction::SwitchInlineCurrentChat(query.into()),
+        )
+    }
+
+    /// A Pay button
+    pub fn pay<T: Into<String>>(text: T) -> Self {
+        Self::new(text, InlineKeyboardButtonAction::Pay)
+    }
+}
+
+/// Names of the fields, one for each known action
+const FIELDS: &[&str] = &[
+    "url",
+    "login_url",
+    "callback_data",
+    "switch_inline_query",
+    "switch_inline_query_current_chat",
+    "callback_game",
+    "pay",
+];
+
+impl Serialize for InlineKeyboardButtonAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+
+        match *self {
+            InlineKeyboardButtonAction::Url(ref url) => map.serialize_entry("url", url)?,
+            InlineKeyboardButtonAction::LoginUrl(ref login_url) => {
+                map.serialize_entry("login_url", login_url)?
+            }
+            InlineKeyboardButtonAction::Callback(ref data) => {
+                map.serialize_entry("callback_data", data)?
+            }
+            InlineKeyboardButtonAction::SwitchInline(ref query) => {
+                map.serialize_entry("switch_inline_query", query)?
+            }
+            InlineKeyboardButtonAction::SwitchInlineCurrentChat(ref query) => {
+                map.serialize_entry("switch_inline_query_current_chat", query)?
+            }
+            InlineKeyboardButtonAction::CallbackGame(ref game) => {
+                map.serialize_entry("callback_game", game)?
+            }
+            InlineKeyboardButtonAction::Pay => map.serialize_entry("pay", &true)?,
+            InlineKeyboardButtonAction::Unknown {
+                ref field,
+                ref value,
+            } => map.serialize_entry(field, value)?,
+        }
+
+        map.end()
+    }
+}
+
+impl InlineKeyboardButtonAction {
+    /// Takes the action out of an InlineKeyboardButton object. Two known
+    /// actions are an error, `"pay": false` counts as no action. Without a
+    /// known action the first field other than `text` is taken as an
+    /// `Unknown` one.
+    fn take_from(fields: &mut Map<String, Value>) -> Result<Self, serde_json::Error> {
+        let mut action: Option<(&str, InlineKeyboardButtonAction)> = None;
+
+        for &key in FIELDS {
+            let value = match key {
+                "url" => take(fields, key)?.map(InlineKeyboardButtonAction::Url),
+                "login_url" => take(fields, key)?.map(InlineKeyboardButtonAction::LoginUrl),
+                "callback_data" => take(fields, key)?.map(InlineKeyboardButtonAction::Callback),
+                "switch_inline_query" => {
+                    take(fields, key)?.map(InlineKeyboardButtonAction::SwitchInline)
+                }
+                "switch_inline_query_current_chat" => {
+                    take(fields, key)?.map(InlineKeyboardButtonAction::SwitchInlineCurrentChat)
+                }
+                "callback_game" => take(fields, key)?.map(InlineKeyboardButtonAction::CallbackGame),
+                "pay" => match take(fields, key)? {
+                    Some(true) => Some(InlineKeyboardButtonAction::Pay),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(value) = value {
+                if let Some((first, _)) = action {
+                    return Err(Error::custom(format_args!(
+                        "inline keyboard button has both `{}` and `{}`",
+                        first, key
+                    )));
+                }
+                action = Some((key, value));
+            }
+        }
+
+        if let Some((_, action)) = action {
+            return Ok(action);
+        }
+
+        let field = fields
+            .keys()
+            .find(|key| *key != "text")
+            .cloned()
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "inline keyboard button has none of {}",
+                    FIELDS.join(", ")
+                ))
+            })?;
+        let value = fields.remove(&field).unwrap_or(Value::Null);
+
+        Ok(InlineKeyboardButtonAction::Unknown { field, value })
+    }
+}
+
+impl<'de> Deserialize<'de> for InlineKeyboardButtonAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fields = Map::deserialize(deserializer)?;
+
+        InlineKeyboardButtonAction::take_from(&mut fields).map_err(D::Error::custom)
+    }
+}
+
+/// The action can't be flattened on deserialization: the field of an
+/// unknown action would go to both the action and `extra`
+impl<'de> Deserialize<'de> for InlineKeyboardButton {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Map::deserialize(deserializer)?;
+
+        button_from(fields).map_err(D::Error::custom)
+    }
+}
+
+fn button_from(mut fields: Map<String, Value>) -> Result<InlineKeyboardButton, serde_json::Error> {
+    Ok(InlineKeyboardButton {
+        text: take(&mut fields, "text")?.ok_or_else(|| Error::missing_field("text"))?,
+        action: InlineKeyboardButtonAction::take_from(&mut fields)?,
+        #[cfg(feature = "extra-fields")]
+        extra: fields.into_iter().collect(),
+    })
+}
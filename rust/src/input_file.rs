@@ -0,0 +1,147 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::{Error as DeError, Unexpected};
+use serde::ser::{Error as SerError, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const ATTACH_PREFIX: &str = "attach://";
+
+/// Name of the struct an upload is serialized as, so the multipart encoder
+/// can tell it from other objects
+pub(crate) const UPLOAD: &str = "$telegram_typings::InputFile::Upload";
+
+/// This object represents the contents of a file to be uploaded. Must be
+/// posted using multipart/form-data in the usual way that files are
+/// uploaded via the browser.
+///
+/// Fields that accept it take either a file that is already stored on the
+/// Telegram servers, an HTTP URL or a new upload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputFile {
+    /// file_id of a file that exists on the Telegram servers
+    FileId(String),
+    /// HTTP URL for Telegram to get the file from the Internet
+    Url(String),
+    /// Another part of the same multipart/form-data request, sent as
+    /// “attach://<file_attach_name>”
+    Attach(String),
+    /// New file posted using multipart/form-data
+    Upload {
+        /// Name of the file reported to Telegram
+        filename: String,
+        /// Contents of the file
+        data: Vec<u8>,
+        /// MIME type of the file, if known
+        mime_type: Option<String>,
+    },
+}
+
+impl InputFile {
+    /// A file that is already stored on the Telegram servers
+    pub fn file_id<T: Into<String>>(file_id: T) -> InputFile {
+        InputFile::FileId(file_id.into())
+    }
+
+    /// A file Telegram should download from the Internet
+    pub fn url<T: Into<String>>(url: T) -> InputFile {
+        InputFile::Url(url.into())
+    }
+
+    /// A file sent in another part of the same request under the given name
+    pub fn attach<T: Into<String>>(name: T) -> InputFile {
+        InputFile::Attach(name.into())
+    }
+
+    /// A new file with the given contents
+    pub fn upload<N: Into<String>, D: Into<Vec<u8>>>(filename: N, data: D) -> InputFile {
+        InputFile::Upload {
+            filename: filename.into(),
+            data: data.into(),
+            mime_type: None,
+        }
+    }
+
+    /// A new file read from the disk, named after the last component of the
+    /// path
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<InputFile> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(InputFile::upload(filename, fs::read(path)?))
+    }
+
+    /// True, if the file has to be sent using multipart/form-data
+    pub fn is_upload(&self) -> bool {
+        matches!(*self, InputFile::Upload { .. })
+    }
+}
+
+/// Contents of an upload, serialized as a byte string
+struct Bytes<'a>(&'a [u8]);
+
+impl<'a> Serialize for Bytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// A file_id or a URL is sent as a bare string, an attached file as
+/// `attach://<name>`.
+///
+/// Uploads can't be a part of a JSON request, so textual formats refuse to
+/// serialize them. Other formats get a struct with the filename, the MIME
+/// type and the contents.
+impl Serialize for InputFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            InputFile::FileId(ref value) | InputFile::Url(ref value) => {
+                serializer.serialize_str(value)
+            }
+            InputFile::Attach(ref name) => {
+                serializer.serialize_str(&format!("{}{}", ATTACH_PREFIX, name))
+            }
+            InputFile::Upload { .. } if serializer.is_human_readable() => Err(S::Error::custom(
+                "uploaded files must be sent using multipart/form-data",
+            )),
+            InputFile::Upload {
+                ref filename,
+                ref data,
+                ref mime_type,
+            } => {
+                let mut state = serializer.serialize_struct(UPLOAD, 3)?;
+                state.serialize_field("filename", filename)?;
+                state.serialize_field("mime_type", mime_type)?;
+                state.serialize_field("data", &Bytes(data))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Strings starting with `http://` or `https://` are URLs, `attach://` refers
+/// to another part of the request, anything else is a file_id
+impl<'de> Deserialize<'de> for InputFile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        if value.is_empty() {
+            return Err(D::Error::invalid_value(
+                Unexpected::Str(""),
+                &"a file_id or a URL",
+            ));
+        }
+
+        if let Some(name) = value.strip_prefix(ATTACH_PREFIX) {
+            Ok(InputFile::Attach(name.to_owned()))
+        } else if value.starts_with("http://") || value.starts_with("https://") {
+            Ok(InputFile::Url(value))
+        } else {
+            Ok(InputFile::FileId(value))
+        }
+    }
+}
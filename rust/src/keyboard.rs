@@ -0,0 +1,392 @@
+use std::error;
+use std::fmt;
+
+use {InlineKeyboardButton, InlineKeyboardMarkup, KeyboardButton, ReplyKeyboardMarkup};
+
+/// Most buttons in a row of an inline keyboard
+pub const INLINE_ROW_LIMIT: usize = 8;
+
+/// Most buttons in an inline keyboard
+pub const INLINE_BUTTON_LIMIT: usize = 100;
+
+/// Most buttons in a row of a reply keyboard
+pub const REPLY_ROW_LIMIT: usize = 12;
+
+/// Most buttons in a reply keyboard
+pub const REPLY_BUTTON_LIMIT: usize = 300;
+
+/// A keyboard Telegram would refuse to show
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyboardError {
+    /// The row with this index has more buttons than allowed
+    RowTooLong {
+        row: usize,
+        buttons: usize,
+        limit: usize,
+    },
+    /// The keyboard has more buttons than allowed
+    TooManyButtons { buttons: usize, limit: usize },
+    /// A grid was asked to lay its buttons out in 0 columns
+    NoColumns,
+}
+
+impl fmt::Display for KeyboardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            KeyboardError::RowTooLong {
+                row,
+                buttons,
+                limit,
+            } => write!(
+                f,
+                "row {} has {} buttons, at most {} are allowed",
+                row, buttons, limit
+            ),
+            KeyboardError::TooManyButtons { buttons, limit } => write!(
+                f,
+                "keyboard has {} buttons, at most {} are allowed",
+                buttons, limit
+            ),
+            KeyboardError::NoColumns => f.write_str("a grid needs at least one column"),
+        }
+    }
+}
+
+impl error::Error for KeyboardError {}
+
+/// Rows of buttons shared by both builders
+#[derive(Debug, Clone)]
+struct Rows<B> {
+    rows: Vec<Vec<Box<B>>>,
+    /// A grid of 0 columns was added, reported by `build`
+    no_columns: bool,
+}
+
+impl<B> Rows<B> {
+    fn new() -> Self {
+        Rows {
+            rows: Vec::new(),
+            no_columns: false,
+        }
+    }
+
+    fn row(&mut self) {
+        self.rows.push(Vec::new());
+    }
+
+    fn button(&mut self, button: B) {
+        if self.rows.is_empty() {
+            self.row();
+        }
+        if let Some(row) = self.rows.last_mut() {
+            row.push(Box::new(button));
+        }
+    }
+
+    fn grid<I: IntoIterator<Item = B>>(&mut self, items: I, columns: usize) {
+        if columns == 0 {
+            self.no_columns = true;
+            return;
+        }
+
+        self.row();
+        for button in items {
+            if self.rows.last().map_or(0, Vec::len) == columns {
+                self.row();
+            }
+            self.button(button);
+        }
+    }
+
+    /// Drops empty rows and checks the grids and the limits
+    fn build(mut self, row_limit: usize, limit: usize) -> Result<Vec<Vec<Box<B>>>, KeyboardError> {
+        if self.no_columns {
+            return Err(KeyboardError::NoColumns);
+        }
+
+        self.rows.retain(|row| !row.is_empty());
+
+        if let Some((row, buttons)) = self
+            .rows
+            .iter()
+            .map(Vec::len)
+            .enumerate()
+            .find(|&(_, buttons)| buttons > row_limit)
+        {
+            return Err(KeyboardError::RowTooLong {
+                row,
+                buttons,
+                limit: row_limit,
+            });
+        }
+
+        let buttons = self.rows.iter().map(Vec::len).sum();
+        if buttons > limit {
+            return Err(KeyboardError::TooManyButtons { buttons, limit });
+        }
+
+        Ok(self.rows)
+    }
+}
+
+/// Builds an `InlineKeyboardMarkup` row by row.
+///
+/// ```
+/// # use telegram_typings::{InlineKeyboardButton, InlineKeyboardMarkup};
+/// let markup = InlineKeyboardMarkup::builder()
+///     .button(InlineKeyboardButton::callback("Yes", "yes"))
+///     .button(InlineKeyboardButton::callback("No", "no"))
+///     .row()
+///     .button(InlineKeyboardButton::url("Help", "https://t.me/botfather"))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(markup.inline_keyboard.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct InlineKeyboardBuilder {
+    rows: Rows<InlineKeyboardButton>,
+}
+
+impl InlineKeyboardBuilder {
+    /// An empty keyboard
+    pub fn new() -> Self {
+        InlineKeyboardBuilder { rows: Rows::new() }
+    }
+
+    /// Starts a new row, buttons added later go there
+    pub fn row(mut self) -> Self {
+        self.rows.row();
+        self
+    }
+
+    /// Adds the button to the last row
+    pub fn button(mut self, button: InlineKeyboardButton) -> Self {
+        self.rows.button(button);
+        self
+    }
+
+    /// Lays the buttons out in new rows of `columns` buttons each, the last
+    /// row may be shorter. `build` fails if `columns` is 0.
+    pub fn grid<I>(mut self, items: I, columns: usize) -> Self
+    where
+        I: IntoIterator<Item = InlineKeyboardButton>,
+    {
+        self.rows.grid(items, columns);
+        self
+    }
+
+    /// Adds a row to move between `pages` pages, numbered from 1, when there
+    /// is more than one: `« 1`, `‹ 4`, `· 5 ·`, `6 ›`, `10 »` for the 5th of
+    /// 10 pages. Each button sends the callback data `data` returns for its
+    /// page.
+    pub fn pagination<F>(mut self, page: usize, pages: usize, data: F) -> Self
+    where
+        F: Fn(usize) -> String,
+    {
+        if pages < 2 {
+            return self;
+        }
+
+        let page = page.max(1).min(pages);
+        let mut buttons = Vec::new();
+
+        if page > 2 {
+            buttons.push(("« 1".to_owned(), 1));
+        }
+        if page > 1 {
+            buttons.push((format!("‹ {}", page - 1), page - 1));
+        }
+        buttons.push((format!("· {} ·", page), page));
+        if page < pages {
+            buttons.push((format!("{} ›", page + 1), page + 1));
+        }
+        if page + 1 < pages {
+            buttons.push((format!("{} »", pages), pages));
+        }
+
+        self.rows.row();
+        for (text, page) in buttons {
+            self.rows
+                .button(InlineKeyboardButton::callback(text, data(page)));
+        }
+        self
+    }
+
+    /// The keyboard, if it fits the limits of Telegram
+    pub fn build(self) -> Result<InlineKeyboardMarkup, KeyboardError> {
+        Ok(InlineKeyboardMarkup {
+            inline_keyboard: self.rows.build(INLINE_ROW_LIMIT, INLINE_BUTTON_LIMIT)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl Default for InlineKeyboardBuilder {
+    fn default() -> Self {
+        InlineKeyboardBuilder::new()
+    }
+}
+
+impl InlineKeyboardMarkup {
+    /// Builds a keyboard row by row
+    pub fn builder() -> InlineKeyboardBuilder {
+        InlineKeyboardBuilder::new()
+    }
+}
+
+/// Builds a `ReplyKeyboardMarkup` row by row. Buttons can be given as plain
+/// strings.
+///
+/// ```
+/// # use telegram_typings::{KeyboardButton, ReplyKeyboardMarkup};
+/// let markup = ReplyKeyboardMarkup::builder()
+///     .grid(vec!["1", "2", "3", "4", "5"], 3)
+///     .row()
+///     .button(KeyboardButton::request_location("Send location"))
+///     .resize_keyboard(true)
+///     .one_time_keyboard(true)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(markup.keyboard.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplyKeyboardBuilder {
+    rows: Rows<KeyboardButton>,
+    resize_keyboard: Option<bool>,
+    one_time_keyboard: Option<bool>,
+    input_field_placeholder: Option<String>,
+    selective: Option<bool>,
+}
+
+impl ReplyKeyboardBuilder {
+    /// An empty keyboard
+    pub fn new() -> Self {
+        ReplyKeyboardBuilder {
+            rows: Rows::new(),
+            resize_keyboard: None,
+            one_time_keyboard: None,
+            input_field_placeholder: None,
+            selective: None,
+        }
+    }
+
+    /// Starts a new row, buttons added later go there
+    pub fn row(mut self) -> Self {
+        self.rows.row();
+        self
+    }
+
+    /// Adds the button to the last row
+    pub fn button<B: Into<KeyboardButton>>(mut self, button: B) -> Self {
+        self.rows.button(button.into());
+        self
+    }
+
+    /// Lays the buttons out in new rows of `columns` buttons each, the last
+    /// row may be shorter. `build` fails if `columns` is 0.
+    pub fn grid<I>(mut self, items: I, columns: usize) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyboardButton>,
+    {
+        self.rows.grid(items.into_iter().map(Into::into), columns);
+        self
+    }
+
+    /// Requests clients to resize the keyboard vertically for optimal fit
+    pub fn resize_keyboard(mut self, resize: bool) -> Self {
+        self.resize_keyboard = Some(resize);
+        self
+    }
+
+    /// Requests clients to hide the keyboard as soon as it's been used
+    pub fn one_time_keyboard(mut self, one_time: bool) -> Self {
+        self.one_time_keyboard = Some(one_time);
+        self
+    }
+
+    /// The placeholder to be shown in the input field when the keyboard is
+    /// active; 1-64 characters
+    pub fn input_field_placeholder<T: Into<String>>(mut self, placeholder: T) -> Self {
+        self.input_field_placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Shows the keyboard to the @mentioned users and the sender of the
+    /// replied message only
+    pub fn selective(mut self, selective: bool) -> Self {
+        self.selective = Some(selective);
+        self
+    }
+
+    /// The keyboard, if it fits the limits of Telegram
+    pub fn build(self) -> Result<ReplyKeyboardMarkup, KeyboardError> {
+        Ok(ReplyKeyboardMarkup {
+            keyboard: self.rows.build(REPLY_ROW_LIMIT, REPLY_BUTTON_LIMIT)?,
+            resize_keyboard: self.resize_keyboard,
+            one_time_keyboard: self.one_time_keyboard,
+            input_field_placeholder: self.input_field_placeholder,
+            selective: self.selective,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl Default for ReplyKeyboardBuilder {
+    fn default() -> Self {
+        ReplyKeyboardBuilder::new()
+    }
+}
+
+impl ReplyKeyboardMarkup {
+    /// Builds a keyboard row by row
+    pub fn builder() -> ReplyKeyboardBuilder {
+        ReplyKeyboardBuilder::new()
+    }
+}
+
+impl KeyboardButton {
+    /// A button sending its text as a message
+    pub fn new<T: Into<String>>(text: T) -> Self {
+        KeyboardButton {
+            text: text.into(),
+            request_contact: None,
+            request_location: None,
+            request_poll: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+
+    /// A button sending the user's phone number, in private chats only
+    pub fn request_contact<T: Into<String>>(text: T) -> Self {
+        KeyboardButton {
+            request_contact: Some(true),
+            ..KeyboardButton::new(text)
+        }
+    }
+
+    /// A button sending the user's current location, in private chats only
+    pub fn request_location<T: Into<String>>(text: T) -> Self {
+        KeyboardButton {
+            request_location: Some(true),
+            ..KeyboardButton::new(text)
+        }
+    }
+}
+
+impl From<String> for KeyboardButton {
+    fn from(text: String) -> KeyboardButton {
+        KeyboardButton::new(text)
+    }
+}
+
+impl<'a> From<&'a str> for KeyboardButton {
+    fn from(text: &'a str) -> KeyboardButton {
+        KeyboardButton::new(text)
+    }
+}
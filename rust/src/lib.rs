@@ -1,37 +1,235 @@
 #[macro_use]
 extern crate serde_derive;
 extern crate serde;
+extern crate serde_json;
+
+#[cfg(feature = "multipart")]
+mod attach;
+mod callback_data;
+mod chat;
+mod chat_member;
+mod id;
+mod inline_keyboard_button;
+mod input_file;
+mod keyboard;
+mod message;
+mod message_entity;
+mod method;
+#[cfg(feature = "multipart")]
+mod multipart;
+mod parse_mode;
+mod recipient;
+mod reply_markup;
+mod response;
+mod unions;
+mod update;
+
+#[cfg(feature = "multipart")]
+pub use attach::attach_media;
+pub use callback_data::{
+    from_callback_data, from_callback_data_with, to_callback_data, to_callback_data_with,
+    CallbackDataError, CallbackStore, MemoryStore, CALLBACK_DATA_LIMIT,
+};
+pub use chat::ChatType;
+pub use id::{
+    CallbackQueryId, ChatId, FileId, FileUniqueId, InlineMessageId, InlineQueryId,
+    MessageIdentifier, PreCheckoutQueryId, ShippingQueryId, UserId,
+};
+pub use inline_keyboard_button::InlineKeyboardButtonAction;
+pub use input_file::InputFile;
+pub use keyboard::{
+    InlineKeyboardBuilder, KeyboardError, ReplyKeyboardBuilder, INLINE_BUTTON_LIMIT,
+    INLINE_ROW_LIMIT, REPLY_BUTTON_LIMIT, REPLY_ROW_LIMIT,
+};
+pub use message::MessageKind;
+pub use message_entity::MessageEntityKind;
+pub use method::{Close, GetMe, GetWebhookInfo, LogOut, MessageOrBool, Method};
+#[cfg(feature = "multipart")]
+pub use multipart::{EncodeError, Multipart, Part, RequestBody};
+pub use parse_mode::ParseMode;
+pub use recipient::{InvalidUsername, Recipient};
+pub use reply_markup::ReplyMarkup;
+pub use response::{ApiError, ApiErrorKind, ApiResponse, ResponseError};
+pub use update::UpdateKind;
+
+/// Unknown fields of an object, see the `extra-fields` feature
+#[cfg(feature = "extra-fields")]
+pub type Extra = std::collections::BTreeMap<String, serde_json::Value>;
 
 /// A placeholder, currently holds no information. Use BotFather to set up
 /// your game.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct CallbackGame {}
+pub struct CallbackGame {
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Contains information about Telegram Passport data shared with the bot by
+/// the user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportData {
+    /// Array with information about documents and other Telegram Passport
+    /// elements that was shared with the bot
+    pub data: Vec<Box<EncryptedPassportElement>>,
+
+    /// Encrypted credentials required to decrypt the data
+    pub credentials: Box<EncryptedCredentials>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Contains information about documents or other Telegram Passport elements
+/// shared with the bot by the user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EncryptedPassportElement {
+    /// Element type. One of “personal_details”, “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport”, “address”, “utility_bill”,
+    /// “bank_statement”, “rental_agreement”, “passport_registration”,
+    /// “temporary_registration”, “phone_number”, “email”.
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded encrypted Telegram Passport element data provided by the
+    /// user, available for “personal_details”, “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport” and “address” types. Can be
+    /// decrypted and verified using the accompanying EncryptedCredentials.
+    /// See https://core.telegram.org/bots/api#encryptedcredentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+
+    /// User's verified phone number, available only for “phone_number” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// User's verified email address, available only for “email” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Array of encrypted files with documents provided by the user, available
+    /// for “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration” and “temporary_registration” types. Files can be
+    /// decrypted and verified using the accompanying EncryptedCredentials.
+    /// See https://core.telegram.org/bots/api#encryptedcredentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<Box<PassportFile>>>,
+
+    /// Encrypted file with the front side of the document, provided by the
+    /// user. Available for “passport”, “driver_license”, “identity_card” and
+    /// “internal_passport”. The file can be decrypted and verified using the
+    /// accompanying EncryptedCredentials.
+    /// See https://core.telegram.org/bots/api#encryptedcredentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<Box<PassportFile>>,
+
+    /// Encrypted file with the reverse side of the document, provided by the
+    /// user. Available for “driver_license” and “identity_card”. The file can
+    /// be decrypted and verified using the accompanying EncryptedCredentials.
+    /// See https://core.telegram.org/bots/api#encryptedcredentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<Box<PassportFile>>,
+
+    /// Encrypted file with the selfie of the user holding a document, provided
+    /// by the user; available for “passport”, “driver_license”, “identity_card”
+    /// and “internal_passport”. The file can be decrypted and verified using
+    /// the accompanying EncryptedCredentials.
+    /// See https://core.telegram.org/bots/api#encryptedcredentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<Box<PassportFile>>,
+
+    /// Array of encrypted files with translated versions of documents provided
+    /// by the user. Available if requested for “passport”, “driver_license”,
+    /// “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”,
+    /// “rental_agreement”, “passport_registration” and “temporary_registration”
+    /// types. Files can be decrypted and verified using the accompanying EncryptedCredentials.
+    /// See https://core.telegram.org/bots/api#encryptedcredentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<Vec<Box<PassportFile>>>,
+
+    /// Base64-encoded element hash for using in PassportElementErrorUnspecified
+    /// See https://core.telegram.org/bots/api#passportelementerrorunspecified
+    pub hash: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
 
-/// This object represents the contents of a file to be uploaded. Must be
-/// posted using multipart/form-data in the usual way that files are
-/// uploaded via the browser.
+/// Contains data required for decrypting and authenticating
+/// EncryptedPassportElement. See the Telegram Passport Documentation for a
+/// complete description of the data decryption and authentication processes.
+/// See https://core.telegram.org/bots/api#encryptedpassportelement
+/// See https://core.telegram.org/passport#receiving-information
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct InputFile {}
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON-serialized data with unique user's
+    /// payload, data hashes and secrets required for EncryptedPassportElement
+    /// decryption and authentication
+    /// See https://core.telegram.org/bots/api#encryptedpassportelement
+    pub data: String,
+
+    /// Base64-encoded data hash for data authentication
+    pub hash: String,
+
+    /// Base64-encoded secret, encrypted with the bot's public RSA key, required
+    /// for data decryption
+    pub secret: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a service message about a voice chat started in
+/// the chat. Currently holds no information.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoiceChatStarted {
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
 
 /// This object represents the content of a message to be sent as a result
 /// of an inline query.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum InputMessageContent {
     InputTextMessageContent(Box<InputTextMessageContent>),
     InputLocationMessageContent(Box<InputLocationMessageContent>),
     InputVenueMessageContent(Box<InputVenueMessageContent>),
     InputContactMessageContent(Box<InputContactMessageContent>),
+    InputInvoiceMessageContent(Box<InputInvoiceMessageContent>),
 }
 
 /// This object represents the content of a media message to be sent.
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
 pub enum InputMedia {
+    #[serde(rename = "photo")]
     InputMediaPhoto(Box<InputMediaPhoto>),
+    #[serde(rename = "video")]
     InputMediaVideo(Box<InputMediaVideo>),
+    #[serde(rename = "audio")]
+    InputMediaAudio(Box<InputMediaAudio>),
+    #[serde(rename = "document")]
+    InputMediaDocument(Box<InputMediaDocument>),
+    #[serde(rename = "animation")]
+    InputMediaAnimation(Box<InputMediaAnimation>),
 }
 
 /// This object represents one result of an inline query
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum InlineQueryResult {
     InlineQueryResultCachedAudio(Box<InlineQueryResultCachedAudio>),
     InlineQueryResultCachedDocument(Box<InlineQueryResultCachedDocument>),
@@ -55,14 +253,79 @@ pub enum InlineQueryResult {
     InlineQueryResultVoice(Box<InlineQueryResultVoice>),
 }
 
+/// This object represents an error in the Telegram Passport element which
+/// was submitted that should be resolved by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "source")]
+pub enum PassportElementError {
+    #[serde(rename = "data")]
+    PassportElementErrorDataField(Box<PassportElementErrorDataField>),
+    #[serde(rename = "front_side")]
+    PassportElementErrorFrontSide(Box<PassportElementErrorFrontSide>),
+    #[serde(rename = "reverse_side")]
+    PassportElementErrorReverseSide(Box<PassportElementErrorReverseSide>),
+    #[serde(rename = "selfie")]
+    PassportElementErrorSelfie(Box<PassportElementErrorSelfie>),
+    #[serde(rename = "file")]
+    PassportElementErrorFile(Box<PassportElementErrorFile>),
+    #[serde(rename = "files")]
+    PassportElementErrorFiles(Box<PassportElementErrorFiles>),
+    #[serde(rename = "translation_file")]
+    PassportElementErrorTranslationFile(Box<PassportElementErrorTranslationFile>),
+    #[serde(rename = "translation_files")]
+    PassportElementErrorTranslationFiles(Box<PassportElementErrorTranslationFiles>),
+    #[serde(rename = "unspecified")]
+    PassportElementErrorUnspecified(Box<PassportElementErrorUnspecified>),
+}
+
+/// This object contains information about one member of a chat. Currently,
+/// the following 6 types of chat members are supported:
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "status")]
+pub enum ChatMember {
+    #[serde(rename = "creator")]
+    ChatMemberOwner(Box<ChatMemberOwner>),
+    #[serde(rename = "administrator")]
+    ChatMemberAdministrator(Box<ChatMemberAdministrator>),
+    #[serde(rename = "member")]
+    ChatMemberMember(Box<ChatMemberMember>),
+    #[serde(rename = "restricted")]
+    ChatMemberRestricted(Box<ChatMemberRestricted>),
+    #[serde(rename = "left")]
+    ChatMemberLeft(Box<ChatMemberLeft>),
+    #[serde(rename = "kicked")]
+    ChatMemberBanned(Box<ChatMemberBanned>),
+}
+
+/// This object represents the scope to which bot commands are applied.
+/// Currently, the following 7 scopes are supported:
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum BotCommandScope {
+    #[serde(rename = "default")]
+    BotCommandScopeDefault(Box<BotCommandScopeDefault>),
+    #[serde(rename = "all_private_chats")]
+    BotCommandScopeAllPrivateChats(Box<BotCommandScopeAllPrivateChats>),
+    #[serde(rename = "all_group_chats")]
+    BotCommandScopeAllGroupChats(Box<BotCommandScopeAllGroupChats>),
+    #[serde(rename = "all_chat_administrators")]
+    BotCommandScopeAllChatAdministrators(Box<BotCommandScopeAllChatAdministrators>),
+    #[serde(rename = "chat")]
+    BotCommandScopeChat(Box<BotCommandScopeChat>),
+    #[serde(rename = "chat_administrators")]
+    BotCommandScopeChatAdministrators(Box<BotCommandScopeChatAdministrators>),
+    #[serde(rename = "chat_member")]
+    BotCommandScopeChatMember(Box<BotCommandScopeChatMember>),
+}
+
 /// This object represents an incoming update.At most one of the optional
 /// parameters can be present in any given update.
 /// See https://core.telegram.org/bots/api#available-types
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Update {
-    /// The update‘s unique identifier. Update identifiers start from a certain
+    /// The update's unique identifier. Update identifiers start from a certain
     /// positive number and increase sequentially. This ID becomes especially
-    /// handy if you’re using Webhooks, since it allows you to ignore repeated
+    /// handy if you're using Webhooks, since it allows you to ignore repeated
     /// updates or to restore the correct update sequence, should they get out
     /// of order. If there are no new updates for at least a week, then
     /// identifier of the next update will be chosen randomly instead of sequentially.
@@ -70,19 +333,24 @@ pub struct Update {
     pub update_id: i64,
 
     /// New incoming message of any kind — text, photo, sticker, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Box<Message>>,
 
     /// New version of a message that is known to the bot and was edited
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_message: Option<Box<Message>>,
 
     /// New incoming channel post of any kind — text, photo, sticker, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_post: Option<Box<Message>>,
 
     /// New version of a channel post that is known to the bot and was edited
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_channel_post: Option<Box<Message>>,
 
     /// New incoming inline query
     /// See https://core.telegram.org/bots/api#inline-mode
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_query: Option<Box<InlineQuery>>,
 
     /// The result of an inline query that was chosen by a user and sent to
@@ -90,16 +358,158 @@ pub struct Update {
     /// collecting for details on how to enable these updates for your bot.
     /// See https://core.telegram.org/bots/api#inline-mode
     /// See https://core.telegram.org/bots/api/bots/inline#collecting-feedback
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub chosen_inline_result: Option<Box<ChosenInlineResult>>,
 
     /// New incoming callback query
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub callback_query: Option<Box<CallbackQuery>>,
 
     /// New incoming shipping query. Only for invoices with flexible price
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_query: Option<Box<ShippingQuery>>,
 
     /// New incoming pre-checkout query. Contains full information about checkout
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pre_checkout_query: Option<Box<PreCheckoutQuery>>,
+
+    /// New poll state. Bots receive only updates about stopped polls and polls,
+    /// which are sent by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Box<Poll>>,
+
+    /// A user changed their answer in a non-anonymous poll. Bots receive new
+    /// votes only in polls that were sent by the bot itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_answer: Option<Box<PollAnswer>>,
+
+    /// The bot's chat member status was updated in a chat. For private chats,
+    /// this update is received only when the bot is blocked or unblocked by the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub my_chat_member: Option<Box<ChatMemberUpdated>>,
+
+    /// A chat member's status was updated in a chat. The bot must be an
+    /// administrator in the chat and must explicitly specify “chat_member” in
+    /// the list of allowed_updates to receive these updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_member: Option<Box<ChatMemberUpdated>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Use this method to receive incoming updates using long polling (wiki).
+/// An Array of Update objects is returned.
+/// See https://en.wikipedia.org/wiki/Push_technology#Long_polling
+/// See https://core.telegram.org/bots/api#update
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetUpdates {
+    /// Identifier of the first update to be returned. Must be greater by one
+    /// than the highest among the identifiers of previously received updates.
+    /// By default, updates starting with the earliest unconfirmed update are
+    /// returned. An update is considered confirmed as soon as getUpdates is
+    /// called with an offset higher than its update_id. The negative offset can
+    /// be specified to retrieve updates starting from -offset update from the
+    /// end of the updates queue. All previous updates will forgotten.
+    /// See https://core.telegram.org/bots/api#getupdates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /// Limits the number of updates to be retrieved. Values between 1-100 are
+    /// accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+
+    /// Timeout in seconds for long polling. Defaults to 0, i.e. usual short
+    /// polling. Should be positive, short polling should be used for testing
+    /// purposes only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+
+    /// A JSON-serialized list of the update types you want your bot to receive.
+    /// For example, specify [“message”, “edited_channel_post”,
+    /// “callback_query”] to only receive updates of these types. See Update for
+    /// a complete list of available update types. Specify an empty list to
+    /// receive all update types except chat_member (default). If not specified,
+    /// the previous setting will be used.Please note that this parameter
+    /// doesn't affect updates created before the call to the getUpdates, so
+    /// unwanted updates may be received for a short period of time.
+    /// See https://core.telegram.org/bots/api#update
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+}
+
+impl Method for GetUpdates {
+    const NAME: &'static str = "getUpdates";
+    type Response = Vec<Update>;
+}
+
+/// Use this method to specify a url and receive incoming updates via an
+/// outgoing webhook. Whenever there is an update for the bot, we will send
+/// an HTTPS POST request to the specified url, containing a JSON-serialized
+/// Update. In case of an unsuccessful request, we will give up after a
+/// reasonable amount of attempts. Returns True on success.
+/// See https://core.telegram.org/bots/api#update
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetWebhook {
+    /// HTTPS url to send updates to. Use an empty string to remove webhook integration
+    pub url: String,
+
+    /// Upload your public key certificate so that the root certificate in use
+    /// can be checked. See our self-signed guide for details.
+    /// See https://core.telegram.org/bots/api/bots/self-signed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<Box<InputFile>>,
+
+    /// The fixed IP address which will be used to send webhook requests instead
+    /// of the IP address resolved through DNS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+
+    /// Maximum allowed number of simultaneous HTTPS connections to the webhook
+    /// for update delivery, 1-100. Defaults to 40. Use lower values to limit
+    /// the load on your bot's server, and higher values to increase your bot's throughput.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<i64>,
+
+    /// A JSON-serialized list of the update types you want your bot to receive.
+    /// For example, specify [“message”, “edited_channel_post”,
+    /// “callback_query”] to only receive updates of these types. See Update for
+    /// a complete list of available update types. Specify an empty list to
+    /// receive all update types except chat_member (default). If not specified,
+    /// the previous setting will be used.Please note that this parameter
+    /// doesn't affect updates created before the call to the setWebhook, so
+    /// unwanted updates may be received for a short period of time.
+    /// See https://core.telegram.org/bots/api#update
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+
+    /// Pass True to drop all pending updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_pending_updates: Option<bool>,
+}
+
+impl Method for SetWebhook {
+    const NAME: &'static str = "setWebhook";
+    type Response = bool;
+}
+
+/// Use this method to remove webhook integration if you decide to switch
+/// back to getUpdates. Returns True on success.
+/// See https://core.telegram.org/bots/api#getupdates
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteWebhook {
+    /// Pass True to drop all pending updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_pending_updates: Option<bool>,
+}
+
+impl Method for DeleteWebhook {
+    const NAME: &'static str = "deleteWebhook";
+    type Response = bool;
 }
 
 /// Contains information about the current status of a webhook.
@@ -114,107 +524,213 @@ pub struct WebhookInfo {
     /// Number of updates awaiting delivery
     pub pending_update_count: i64,
 
+    /// Currently used webhook IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+
     /// Unix time for the most recent error that happened when trying to deliver
     /// an update via webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_error_date: Option<i64>,
 
     /// Error message in human-readable format for the most recent error that
     /// happened when trying to deliver an update via webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_error_message: Option<String>,
 
     /// Maximum allowed number of simultaneous HTTPS connections to the webhook
     /// for update delivery
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_connections: Option<i64>,
 
-    /// A list of update types the bot is subscribed to. Defaults to all update types
+    /// A list of update types the bot is subscribed to. Defaults to all update
+    /// types except chat_member
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_updates: Option<Vec<String>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a Telegram user or bot.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
-    /// Unique identifier for this user or bot
-    pub id: i64,
+    /// Unique identifier for this user or bot. This number may have more than
+    /// 32 significant bits and some programming languages may have
+    /// difficulty/silent defects in interpreting it. But it has at most 52
+    /// significant bits, so a 64-bit integer or double-precision float type are
+    /// safe for storing this identifier.
+    pub id: UserId,
 
     /// True, if this user is a bot
     pub is_bot: bool,
 
-    /// User‘s or bot’s first name
+    /// User's or bot's first name
     pub first_name: String,
 
-    /// User‘s or bot’s last name
+    /// User's or bot's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
-    /// User‘s or bot’s username
+    /// User's or bot's username
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 
     /// IETF language tag of the user's language
     /// See https://en.wikipedia.org/wiki/IETF_language_tag
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
+
+    /// True, if the bot can be invited to groups. Returned only in getMe.
+    /// See https://core.telegram.org/bots/api#getme
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_join_groups: Option<bool>,
+
+    /// True, if privacy mode is disabled for the bot. Returned only in getMe.
+    /// See https://core.telegram.org/bots#privacy-mode
+    /// See https://core.telegram.org/bots/api#getme
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_read_all_group_messages: Option<bool>,
+
+    /// True, if the bot supports inline queries. Returned only in getMe.
+    /// See https://core.telegram.org/bots/api#getme
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_inline_queries: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a chat.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Chat {
-    /// Unique identifier for this chat. This number may be greater than 32 bits
-    /// and some programming languages may have difficulty/silent defects in
-    /// interpreting it. But it is smaller than 52 bits, so a signed 64 bit
-    /// integer or double-precision float type are safe for storing this identifier.
-    pub id: i64,
+    /// Unique identifier for this chat. This number may have more than 32
+    /// significant bits and some programming languages may have
+    /// difficulty/silent defects in interpreting it. But it has at most 52
+    /// significant bits, so a signed 64-bit integer or double-precision float
+    /// type are safe for storing this identifier.
+    pub id: ChatId,
 
     /// Type of chat, can be either “private”, “group”, “supergroup” or “channel”
     #[serde(rename = "type")]
-    pub type_tl: String,
+    pub type_tl: ChatType,
 
     /// Title, for supergroups, channels and group chats
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
     /// Username, for private chats, supergroups and channels if available
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 
     /// First name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
 
     /// Last name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
-    /// True if a group has ‘All Members Are Admins’ enabled.
-    pub all_members_are_administrators: Option<bool>,
-
     /// Chat photo. Returned only in getChat.
     /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo: Option<Box<ChatPhoto>>,
 
-    /// Description, for supergroups and channel chats. Returned only in getChat.
+    /// Bio of the other party in a private chat. Returned only in getChat.
+    /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+
+    /// Description, for groups, supergroups and channel chats. Returned only in getChat.
     /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Chat invite link, for supergroups and channel chats. Returned only in getChat.
+    /// Primary invite link, for groups, supergroups and channel chats. Returned
+    /// only in getChat.
     /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invite_link: Option<String>,
 
-    /// Pinned message, for supergroups and channel chats. Returned only in getChat.
+    /// The most recent pinned message (by sending date). Returned only in getChat.
     /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_message: Option<Box<Message>>,
 
+    /// Default chat member permissions, for groups and supergroups. Returned
+    /// only in getChat.
+    /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Box<ChatPermissions>>,
+
+    /// For supergroups, the minimum allowed delay between consecutive messages
+    /// sent by each unpriviledged user. Returned only in getChat.
+    /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slow_mode_delay: Option<i64>,
+
+    /// The time after which all messages sent to the chat will be automatically
+    /// deleted; in seconds. Returned only in getChat.
+    /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_auto_delete_time: Option<i64>,
+
     /// For supergroups, name of group sticker set. Returned only in getChat.
     /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker_set_name: Option<String>,
 
     /// True, if the bot can change the group sticker set. Returned only in getChat.
     /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_set_sticker_set: Option<bool>,
+
+    /// Unique identifier for the linked chat, i.e. the discussion group
+    /// identifier for a channel and vice versa; for supergroups and channel
+    /// chats. This identifier may be greater than 32 bits and some programming
+    /// languages may have difficulty/silent defects in interpreting it. But it
+    /// is smaller than 52 bits, so a signed 64 bit integer or double-precision
+    /// float type are safe for storing this identifier. Returned only in getChat.
+    /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_chat_id: Option<ChatId>,
+
+    /// For supergroups, the location to which the supergroup is connected.
+    /// Returned only in getChat.
+    /// See https://core.telegram.org/bots/api#getchat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Box<ChatLocation>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a message.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Message {
     /// Unique message identifier inside this chat
-    pub message_id: i64,
+    pub message_id: MessageIdentifier,
 
     /// Sender, empty for messages sent to channels
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from: Option<Box<User>>,
 
+    /// Sender of the message, sent on behalf of a chat. The channel itself for
+    /// channel messages. The supergroup itself for messages from anonymous
+    /// group administrators. The linked channel for messages automatically
+    /// forwarded to the discussion group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_chat: Option<Box<Chat>>,
+
     /// Date the message was sent in Unix time
     pub date: i64,
 
@@ -222,162 +738,281 @@ pub struct Message {
     pub chat: Box<Chat>,
 
     /// For forwarded messages, sender of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from: Option<Box<User>>,
 
-    /// For messages forwarded from channels, information about the original channel
+    /// For messages forwarded from channels or from anonymous administrators,
+    /// information about the original sender chat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from_chat: Option<Box<Chat>>,
 
     /// For messages forwarded from channels, identifier of the original message
     /// in the channel
-    pub forward_from_message_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_from_message_id: Option<MessageIdentifier>,
 
     /// For messages forwarded from channels, signature of the post author if present
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_signature: Option<String>,
 
+    /// Sender's name for messages forwarded from users who disallow adding a
+    /// link to their account in forwarded messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_sender_name: Option<String>,
+
     /// For forwarded messages, date the original message was sent in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_date: Option<i64>,
 
     /// For replies, the original message. Note that the Message object in this
     /// field will not contain further reply_to_message fields even if it itself
     /// is a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message: Option<Box<Message>>,
 
+    /// Bot through which the message was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_bot: Option<Box<User>>,
+
     /// Date the message was last edited in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edit_date: Option<i64>,
 
     /// The unique identifier of a media message group this message belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub media_group_id: Option<String>,
 
-    /// Signature of the post author for messages in channels
+    /// Signature of the post author for messages in channels, or the custom
+    /// title of an anonymous group administrator
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_signature: Option<String>,
 
-    /// For text messages, the actual UTF-8 text of the message, 0-4096 characters.
+    /// For text messages, the actual UTF-8 text of the message, 0-4096 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
     /// For text messages, special entities like usernames, URLs, bot commands,
     /// etc. that appear in the text
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<Box<MessageEntity>>>,
 
-    /// For messages with a caption, special entities like usernames, URLs, bot
-    /// commands, etc. that appear in the caption
-    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+    /// Message is an animation, information about the animation. For backward
+    /// compatibility, when this field is set, the document field will also be set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Box<Animation>>,
 
     /// Message is an audio file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub audio: Option<Box<Audio>>,
 
     /// Message is a general file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub document: Option<Box<Document>>,
 
-    /// Message is a game, information about the game. More about games »
-    /// See https://core.telegram.org/bots/api#games
-    pub game: Option<Box<Game>>,
-
     /// Message is a photo, available sizes of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo: Option<Vec<Box<PhotoSize>>>,
 
     /// Message is a sticker, information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker: Option<Box<Sticker>>,
 
     /// Message is a video, information about the video
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<Box<Video>>,
 
-    /// Message is a voice message, information about the file
-    pub voice: Option<Box<Voice>>,
-
     /// Message is a video note, information about the video message
     /// See https://telegram.org/blog/video-messages-and-telescope
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_note: Option<Box<VideoNote>>,
 
-    /// Caption for the audio, document, photo, video or voice, 0-200 characters
+    /// Message is a voice message, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Box<Voice>>,
+
+    /// Caption for the animation, audio, document, photo, video or voice,
+    /// 0-1024 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
+    /// For messages with a caption, special entities like usernames, URLs, bot
+    /// commands, etc. that appear in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
     /// Message is a shared contact, information about the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub contact: Option<Box<Contact>>,
 
-    /// Message is a shared location, information about the location
-    pub location: Option<Box<Location>>,
+    /// Message is a dice with random value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dice: Option<Box<Dice>>,
+
+    /// Message is a game, information about the game. More about games »
+    /// See https://core.telegram.org/bots/api#games
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game: Option<Box<Game>>,
+
+    /// Message is a native poll, information about the poll
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Box<Poll>>,
 
-    /// Message is a venue, information about the venue
+    /// Message is a venue, information about the venue. For backward
+    /// compatibility, when this field is set, the location field will also be set
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub venue: Option<Box<Venue>>,
 
+    /// Message is a shared location, information about the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Box<Location>>,
+
     /// New members that were added to the group or supergroup and information
     /// about them (the bot itself may be one of these members)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_chat_members: Option<Vec<Box<User>>>,
 
     /// A member was removed from the group, information about them (this member
     /// may be the bot itself)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub left_chat_member: Option<Box<User>>,
 
     /// A chat title was changed to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_chat_title: Option<String>,
 
     /// A chat photo was change to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_chat_photo: Option<Vec<Box<PhotoSize>>>,
 
     /// Service message: the chat photo was deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub delete_chat_photo: Option<bool>,
 
     /// Service message: the group has been created
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group_chat_created: Option<bool>,
 
-    /// Service message: the supergroup has been created. This field can‘t be
-    /// received in a message coming through updates, because bot can’t be a
+    /// Service message: the supergroup has been created. This field can't be
+    /// received in a message coming through updates, because bot can't be a
     /// member of a supergroup when it is created. It can only be found in
     /// reply_to_message if someone replies to a very first message in a
     /// directly created supergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub supergroup_chat_created: Option<bool>,
 
-    /// Service message: the channel has been created. This field can‘t be
-    /// received in a message coming through updates, because bot can’t be a
+    /// Service message: the channel has been created. This field can't be
+    /// received in a message coming through updates, because bot can't be a
     /// member of a channel when it is created. It can only be found in
     /// reply_to_message if someone replies to a very first message in a channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_chat_created: Option<bool>,
 
+    /// Service message: auto-delete timer settings changed in the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_auto_delete_timer_changed: Option<Box<MessageAutoDeleteTimerChanged>>,
+
     /// The group has been migrated to a supergroup with the specified
-    /// identifier. This number may be greater than 32 bits and some programming
-    /// languages may have difficulty/silent defects in interpreting it. But it
-    /// is smaller than 52 bits, so a signed 64 bit integer or double-precision
-    /// float type are safe for storing this identifier.
-    pub migrate_to_chat_id: Option<i64>,
+    /// identifier. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting
+    /// it. But it has at most 52 significant bits, so a signed 64-bit integer
+    /// or double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<ChatId>,
 
     /// The supergroup has been migrated from a group with the specified
-    /// identifier. This number may be greater than 32 bits and some programming
-    /// languages may have difficulty/silent defects in interpreting it. But it
-    /// is smaller than 52 bits, so a signed 64 bit integer or double-precision
-    /// float type are safe for storing this identifier.
-    pub migrate_from_chat_id: Option<i64>,
+    /// identifier. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting
+    /// it. But it has at most 52 significant bits, so a signed 64-bit integer
+    /// or double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_from_chat_id: Option<ChatId>,
 
     /// Specified message was pinned. Note that the Message object in this field
     /// will not contain further reply_to_message fields even if it is itself a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_message: Option<Box<Message>>,
 
     /// Message is an invoice for a payment, information about the invoice. More
     /// about payments »
     /// See https://core.telegram.org/bots/api#payments
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice: Option<Box<Invoice>>,
 
     /// Message is a service message about a successful payment, information
     /// about the payment. More about payments »
     /// See https://core.telegram.org/bots/api#payments
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub successful_payment: Option<Box<SuccessfulPayment>>,
 
     /// The domain name of the website on which the user has logged in. More
     /// about Telegram Login »
     /// See https://core.telegram.org/bots/api/widgets/login
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connected_website: Option<String>,
+
+    /// Telegram Passport data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport_data: Option<Box<PassportData>>,
+
+    /// Service message. A user in the chat triggered another user's proximity
+    /// alert while sharing Live Location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_triggered: Option<Box<ProximityAlertTriggered>>,
+
+    /// Service message: voice chat scheduled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_chat_scheduled: Option<Box<VoiceChatScheduled>>,
+
+    /// Service message: voice chat started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_chat_started: Option<Box<VoiceChatStarted>>,
+
+    /// Service message: voice chat ended
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_chat_ended: Option<Box<VoiceChatEnded>>,
+
+    /// Service message: new participants invited to a voice chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_chat_participants_invited: Option<Box<VoiceChatParticipantsInvited>>,
+
+    /// Inline keyboard attached to the message. login_url buttons are
+    /// represented as ordinary url buttons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a unique message identifier.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageId {
+    /// Unique message identifier
+    pub message_id: MessageIdentifier,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents one special entity in a text message. For
 /// example, hashtags, usernames, URLs, etc.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 pub struct MessageEntity {
-    /// Type of the entity. Can be mention (@username), hashtag, bot_command,
-    /// url, email, bold (bold text), italic (italic text), code (monowidth
-    /// string), pre (monowidth block), text_link (for clickable text URLs),
-    /// text_mention (for users without usernames)
+    /// Type of the entity. Can be mention (@username), hashtag, cashtag,
+    /// bot_command, url, email, phone_number, bold (bold text), italic (italic
+    /// text), underline, strikethrough, code (monowidth string), pre (monowidth
+    /// block), text_link (for clickable text URLs), text_mention (for users
+    /// without usernames). Carries the url of a text_link, the user of a
+    /// text_mention and the language of a pre entity.
     /// See https://telegram.org/blog/edit#new-mentions
-    #[serde(rename = "type")]
-    pub type_tl: String,
+    #[serde(flatten)]
+    pub kind: MessageEntityKind,
 
     /// Offset in UTF-16 code units to the start of the entity
     pub offset: i64,
@@ -385,11 +1020,11 @@ pub struct MessageEntity {
     /// Length of the entity in UTF-16 code units
     pub length: i64,
 
-    /// For “text_link” only, url that will be opened after user taps on the text
-    pub url: Option<String>,
-
-    /// For “text_mention” only, the mentioned user
-    pub user: Option<Box<User>>,
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents one size of a photo or a file / sticker thumbnail.
@@ -397,8 +1032,12 @@ pub struct MessageEntity {
 /// See https://core.telegram.org/bots/api#sticker
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PhotoSize {
-    /// Unique identifier for this file
-    pub file_id: String,
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
     /// Photo width
     pub width: i64,
@@ -407,30 +1046,102 @@ pub struct PhotoSize {
     pub height: i64,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents an animation file (GIF or H.264/MPEG-4 AVC video
+/// without sound).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Animation {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
+
+    /// Video width as defined by sender
+    pub width: i64,
+
+    /// Video height as defined by sender
+    pub height: i64,
+
+    /// Duration of the video in seconds as defined by sender
+    pub duration: i64,
+
+    /// Animation thumbnail as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<PhotoSize>>,
+
+    /// Original animation filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents an audio file to be treated as music by the
 /// Telegram clients.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Audio {
-    /// Unique identifier for this file
-    pub file_id: String,
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
     /// Duration of the audio in seconds as defined by sender
     pub duration: i64,
 
     /// Performer of the audio as defined by sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub performer: Option<String>,
 
     /// Title of the audio as defined by sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
+    /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
     /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Thumbnail of the album cover to which the music file belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<PhotoSize>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a general file (as opposed to photos, voice
@@ -440,27 +1151,45 @@ pub struct Audio {
 /// See https://core.telegram.org/bots/api#audio
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Document {
-    /// Unique file identifier
-    pub file_id: String,
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
     /// Document thumbnail as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<Box<PhotoSize>>,
 
     /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_name: Option<String>,
 
     /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a video file.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Video {
-    /// Unique identifier for this file
-    pub file_id: String,
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
     /// Video width as defined by sender
     pub width: i64,
@@ -472,49 +1201,86 @@ pub struct Video {
     pub duration: i64,
 
     /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<Box<PhotoSize>>,
 
+    /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
     /// Mime type of a file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// This object represents a voice note.
+/// This object represents a video message (available in Telegram apps as of v.4.0).
+/// See https://telegram.org/blog/video-messages-and-telescope
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Voice {
-    /// Unique identifier for this file
-    pub file_id: String,
+pub struct VideoNote {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
 
-    /// Duration of the audio in seconds as defined by sender
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
+
+    /// Video width and height (diameter of the video message) as defined by sender
+    pub length: i64,
+
+    /// Duration of the video in seconds as defined by sender
     pub duration: i64,
 
-    /// MIME type of the file as defined by sender
-    pub mime_type: Option<String>,
+    /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<PhotoSize>>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// This object represents a video message (available in Telegram apps as of v.4.0).
-/// See https://telegram.org/blog/video-messages-and-telescope
+/// This object represents a voice note.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct VideoNote {
-    /// Unique identifier for this file
-    pub file_id: String,
+pub struct Voice {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
 
-    /// Video width and height as defined by sender
-    pub length: i64,
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
-    /// Duration of the video in seconds as defined by sender
+    /// Duration of the audio in seconds as defined by sender
     pub duration: i64,
 
-    /// Video thumbnail
-    pub thumb: Option<Box<PhotoSize>>,
+    /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a phone contact.
@@ -527,10 +1293,139 @@ pub struct Contact {
     pub first_name: String,
 
     /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
-    /// Contact's user identifier in Telegram
-    pub user_id: Option<i64>,
+    /// Contact's user identifier in Telegram. This number may have more than 32
+    /// significant bits and some programming languages may have
+    /// difficulty/silent defects in interpreting it. But it has at most 52
+    /// significant bits, so a 64-bit integer or double-precision float type are
+    /// safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+
+    /// Additional data about the contact in the form of a vCard
+    /// See https://en.wikipedia.org/wiki/VCard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents an animated emoji that displays a random value.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Dice {
+    /// Emoji on which the dice throw animation is based
+    pub emoji: String,
+
+    /// Value of the dice, 1-6 for “”, “” and “” base emoji, 1-5 for “” and “”
+    /// base emoji, 1-64 for “” base emoji
+    pub value: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object contains information about one answer option in a poll.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PollOption {
+    /// Option text, 1-100 characters
+    pub text: String,
+
+    /// Number of users that voted for this option
+    pub voter_count: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents an answer of a user in a non-anonymous poll.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PollAnswer {
+    /// Unique poll identifier
+    pub poll_id: String,
+
+    /// The user, who changed the answer to the poll
+    pub user: Box<User>,
+
+    /// 0-based identifiers of answer options, chosen by the user. May be empty
+    /// if the user retracted their vote.
+    pub option_ids: Vec<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object contains information about a poll.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Poll {
+    /// Unique poll identifier
+    pub id: String,
+
+    /// Poll question, 1-300 characters
+    pub question: String,
+
+    /// List of poll options
+    pub options: Vec<Box<PollOption>>,
+
+    /// Total number of users that voted in the poll
+    pub total_voter_count: i64,
+
+    /// True, if the poll is closed
+    pub is_closed: bool,
+
+    /// True, if the poll is anonymous
+    pub is_anonymous: bool,
+
+    /// Poll type, currently can be “regular” or “quiz”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// True, if the poll allows multiple answers
+    pub allows_multiple_answers: bool,
+
+    /// 0-based identifier of the correct answer option. Available only for
+    /// polls in the quiz mode, which are closed, or was sent (not forwarded) by
+    /// the bot or to the private chat with the bot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<i64>,
+
+    /// Text that is shown when a user chooses an incorrect answer or taps on
+    /// the lamp icon in a quiz-style poll, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+
+    /// Special entities like usernames, URLs, bot commands, etc. that appear in
+    /// the explanation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Amount of time in seconds the poll will be active after creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i64>,
+
+    /// Point in time (Unix timestamp) when the poll will be automatically closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a point on the map.
@@ -541,12 +1436,37 @@ pub struct Location {
 
     /// Latitude as defined by sender
     pub latitude: f64,
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Time relative to the message sending date, during which the location can
+    /// be updated, in seconds. For active live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+
+    /// The direction in which user is moving, in degrees; 1-360. For active
+    /// live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+
+    /// Maximum distance for proximity alerts about approaching another chat
+    /// member, in meters. For sent live locations only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a venue.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Venue {
-    /// Venue location
+    /// Venue location. Can't be a live location
     pub location: Box<Location>,
 
     /// Name of the venue
@@ -556,7 +1476,105 @@ pub struct Venue {
     pub address: String,
 
     /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_id: Option<String>,
+
+    /// Foursquare type of the venue. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+
+    /// Google Places type of the venue. (See supported types.)
+    /// See https://developers.google.com/places/web-service/supported_types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents the content of a service message, sent whenever a
+/// user in the chat triggers a proximity alert set by another user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProximityAlertTriggered {
+    /// User that triggered the alert
+    pub traveler: Box<User>,
+
+    /// User that set the alert
+    pub watcher: Box<User>,
+
+    /// The distance between the users
+    pub distance: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a service message about a change in auto-delete
+/// timer settings.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageAutoDeleteTimerChanged {
+    /// New auto-delete time for messages in the chat
+    pub message_auto_delete_time: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a service message about a voice chat scheduled in
+/// the chat.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoiceChatScheduled {
+    /// Point in time (Unix timestamp) when the voice chat is supposed to be
+    /// started by a chat administrator
+    pub start_date: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a service message about a voice chat ended in the chat.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoiceChatEnded {
+    /// Voice chat duration; in seconds
+    pub duration: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a service message about new members invited to a
+/// voice chat.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoiceChatParticipantsInvited {
+    /// New members that were invited to the voice chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<Box<User>>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represent a user's profile pictures.
@@ -567,6 +1585,12 @@ pub struct UserProfilePhotos {
 
     /// Requested profile pictures (in up to 4 sizes each)
     pub photos: Vec<Vec<Box<PhotoSize>>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a file ready to be downloaded. The file can be
@@ -577,15 +1601,27 @@ pub struct UserProfilePhotos {
 /// See https://core.telegram.org/bots/api#getfile
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct File {
-    /// Unique identifier for this file
-    pub file_id: String,
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
     /// File size, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 
     /// File path. Use https://api.telegram.org/file/bot<token>/<file_path> to
     /// get the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_path: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a custom keyboard with reply options (see
@@ -601,27 +1637,42 @@ pub struct ReplyKeyboardMarkup {
     /// (e.g., make the keyboard smaller if there are just two rows of buttons).
     /// Defaults to false, in which case the custom keyboard is always of the
     /// same height as the app's standard keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resize_keyboard: Option<bool>,
 
     /// Requests clients to hide the keyboard as soon as it's been used. The
     /// keyboard will still be available, but clients will automatically display
     /// the usual letter-keyboard in the chat – the user can press a special
     /// button in the input field to see the custom keyboard again. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub one_time_keyboard: Option<bool>,
 
+    /// The placeholder to be shown in the input field when the keyboard is
+    /// active; 1-64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_field_placeholder: Option<String>,
+
     /// Use this parameter if you want to show the keyboard to specific users
     /// only. Targets: 1) users that are @mentioned in the text of the Message
     /// object; 2) if the bot's message is a reply (has reply_to_message_id),
     /// sender of the original message.Example: A user requests to change the
-    /// bot‘s language, bot replies to the request with a keyboard to select the
-    /// new language. Other users in the group don’t see the keyboard.
+    /// bot's language, bot replies to the request with a keyboard to select the
+    /// new language. Other users in the group don't see the keyboard.
     /// See https://core.telegram.org/bots/api#message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub selective: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents one button of the reply keyboard. For simple text
 /// buttons String can be used instead of this object to specify text of the
-/// button. Optional fields are mutually exclusive.
+/// button. Optional fields request_contact, request_location, and
+/// request_poll are mutually exclusive.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct KeyboardButton {
     /// Text of the button. If none of the optional fields are used, it will be
@@ -630,11 +1681,42 @@ pub struct KeyboardButton {
 
     /// If True, the user's phone number will be sent as a contact when the
     /// button is pressed. Available in private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_contact: Option<bool>,
 
     /// If True, the user's current location will be sent when the button is
     /// pressed. Available in private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_location: Option<bool>,
+
+    /// If specified, the user will be asked to create a poll and send it to the
+    /// bot when the button is pressed. Available in private chats only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_poll: Option<Box<KeyboardButtonPollType>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents type of a poll, which is allowed to be created
+/// and sent when the corresponding button is pressed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeyboardButtonPollType {
+    /// If quiz is passed, the user will be allowed to create only polls in the
+    /// quiz mode. If regular is passed, only regular polls will be allowed.
+    /// Otherwise, the user will be allowed to create a poll of any type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_tl: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Upon receiving a message with this object, Telegram clients will remove
@@ -659,7 +1741,14 @@ pub struct ReplyKeyboardRemove {
     /// keyboard for that user, while still showing the keyboard with poll
     /// options to users who haven't voted yet.
     /// See https://core.telegram.org/bots/api#message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub selective: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents an inline keyboard that appears right next to the
@@ -671,51 +1760,72 @@ pub struct InlineKeyboardMarkup {
     /// InlineKeyboardButton objects
     /// See https://core.telegram.org/bots/api#inlinekeyboardbutton
     pub inline_keyboard: Vec<Vec<Box<InlineKeyboardButton>>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents one button of an inline keyboard. You must use
 /// exactly one of the optional fields.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 pub struct InlineKeyboardButton {
     /// Label text on the button
     pub text: String,
 
-    /// HTTP url to be opened when button is pressed
-    pub url: Option<String>,
+    /// What happens when the button is pressed
+    #[serde(flatten)]
+    pub action: InlineKeyboardButtonAction,
 
-    /// Data to be sent in a callback query to the bot when button is pressed,
-    /// 1-64 bytes
-    /// See https://core.telegram.org/bots/api#callbackquery
-    pub callback_data: Option<String>,
-
-    /// If set, pressing the button will prompt the user to select one of their
-    /// chats, open that chat and insert the bot‘s username and the specified
-    /// inline query in the input field. Can be empty, in which case just the
-    /// bot’s username will be inserted.Note: This offers an easy way for users
-    /// to start using your bot in inline mode when they are currently in a
-    /// private chat with it. Especially useful when combined with switch_pm…
-    /// actions – in this case the user will be automatically returned to the
-    /// chat they switched from, skipping the chat selection screen.
-    /// See https://core.telegram.org/bots/api/bots/inline
-    /// See https://core.telegram.org/bots/api#answerinlinequery
-    pub switch_inline_query: Option<String>,
-
-    /// If set, pressing the button will insert the bot‘s username and the
-    /// specified inline query in the current chat's input field. Can be empty,
-    /// in which case only the bot’s username will be inserted.This offers a
-    /// quick way for the user to open your bot in inline mode in the same chat
-    /// – good for selecting something from multiple options.
-    pub switch_inline_query_current_chat: Option<String>,
-
-    /// Description of the game that will be launched when the user presses the
-    /// button.NOTE: This type of button must always be the first button in the
-    /// first row.
-    pub callback_game: Option<Box<CallbackGame>>,
-
-    /// Specify True, to send a Pay button.NOTE: This type of button must always
-    /// be the first button in the first row.
-    /// See https://core.telegram.org/bots/api#payments
-    pub pay: Option<bool>,
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a parameter of the inline keyboard button used to
+/// automatically authorize a user. Serves as a great replacement for the
+/// Telegram Login Widget when the user is coming from Telegram. All the
+/// user needs to do is tap/click a button and confirm that they want to log in:
+/// See https://core.telegram.org/widgets/login
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoginUrl {
+    /// An HTTP URL to be opened with user authorization data added to the query
+    /// string when the button is pressed. If the user refuses to provide
+    /// authorization data, the original URL without information about the user
+    /// will be opened. The data added is the same as described in Receiving
+    /// authorization data.NOTE: You must always check the hash of the received
+    /// data to verify the authentication and the integrity of the data as
+    /// described in Checking authorization.
+    /// See https://core.telegram.org/widgets/login#receiving-authorization-data
+    /// See https://core.telegram.org/widgets/login#checking-authorization
+    pub url: String,
+
+    /// New text of the button in forwarded messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_text: Option<String>,
+
+    /// Username of a bot, which will be used for user authorization. See
+    /// Setting up a bot for more details. If not specified, the current bot's
+    /// username will be assumed. The url's domain must be the same as the
+    /// domain linked with the bot. See Linking your domain to the bot for more details.
+    /// See https://core.telegram.org/widgets/login#setting-up-a-bot
+    /// See https://core.telegram.org/widgets/login#linking-your-domain-to-the-bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_username: Option<String>,
+
+    /// Pass True to request the permission for your bot to send messages to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_write_access: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents an incoming callback query from a callback button
@@ -729,7 +1839,7 @@ pub struct InlineKeyboardButton {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CallbackQuery {
     /// Unique identifier for this query
-    pub id: String,
+    pub id: CallbackQueryId,
 
     /// Sender
     pub from: Box<User>,
@@ -737,11 +1847,13 @@ pub struct CallbackQuery {
     /// Message with the callback button that originated the query. Note that
     /// message content and message date will not be available if the message is
     /// too old
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Box<Message>>,
 
     /// Identifier of the message sent via the bot in inline mode, that
     /// originated the query.
-    pub inline_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
 
     /// Global identifier, uniquely corresponding to the chat to which the
     /// message with the callback button was sent. Useful for high scores in games.
@@ -750,199 +1862,2868 @@ pub struct CallbackQuery {
 
     /// Data associated with the callback button. Be aware that a bad client can
     /// send arbitrary data in this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
 
     /// Short name of a Game to be returned, serves as the unique identifier for
     /// the game
     /// See https://core.telegram.org/bots/api#games
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game_short_name: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Upon receiving a message with this object, Telegram clients will display
-/// a reply interface to the user (act as if the user has selected the bot‘s
-/// message and tapped ’Reply'). This can be extremely useful if you want to
+/// a reply interface to the user (act as if the user has selected the bot's
+/// message and tapped 'Reply'). This can be extremely useful if you want to
 /// create user-friendly step-by-step interfaces without having to sacrifice
 /// privacy mode.
 /// See https://core.telegram.org/bots/api/bots#privacy-mode
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ForceReply {
     /// Shows reply interface to the user, as if they manually selected the
-    /// bot‘s message and tapped ’Reply'
+    /// bot's message and tapped 'Reply'
     pub force_reply: bool,
 
-    /// Use this parameter if you want to force reply from specific users only.
-    /// Targets: 1) users that are @mentioned in the text of the Message object;
-    /// 2) if the bot's message is a reply (has reply_to_message_id), sender of
-    /// the original message.
-    /// See https://core.telegram.org/bots/api#message
-    pub selective: Option<bool>,
+    /// The placeholder to be shown in the input field when the reply is active;
+    /// 1-64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_field_placeholder: Option<String>,
+
+    /// Use this parameter if you want to force reply from specific users only.
+    /// Targets: 1) users that are @mentioned in the text of the Message object;
+    /// 2) if the bot's message is a reply (has reply_to_message_id), sender of
+    /// the original message.
+    /// See https://core.telegram.org/bots/api#message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a chat photo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatPhoto {
+    /// File identifier of small (160x160) chat photo. This file_id can be used
+    /// only for photo download and only for as long as the photo is not changed.
+    pub small_file_id: FileId,
+
+    /// Unique file identifier of small (160x160) chat photo, which is supposed
+    /// to be the same over time and for different bots. Can't be used to
+    /// download or reuse the file.
+    pub small_file_unique_id: FileUniqueId,
+
+    /// File identifier of big (640x640) chat photo. This file_id can be used
+    /// only for photo download and only for as long as the photo is not changed.
+    pub big_file_id: FileId,
+
+    /// Unique file identifier of big (640x640) chat photo, which is supposed to
+    /// be the same over time and for different bots. Can't be used to download
+    /// or reuse the file.
+    pub big_file_unique_id: FileUniqueId,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an invite link for a chat.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatInviteLink {
+    /// The invite link. If the link was created by another chat administrator,
+    /// then the second part of the link will be replaced with “…”.
+    pub invite_link: String,
+
+    /// Creator of the link
+    pub creator: Box<User>,
+
+    /// True, if the link is primary
+    pub is_primary: bool,
+
+    /// True, if the link is revoked
+    pub is_revoked: bool,
+
+    /// Point in time (Unix timestamp) when the link will expire or has been expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+
+    /// Maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a chat member that owns the chat and has all administrator privileges.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberOwner {
+    /// Information about the user
+    pub user: Box<User>,
+
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: bool,
+
+    /// Custom title for this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_title: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a chat member that has some additional privileges.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberAdministrator {
+    /// Information about the user
+    pub user: Box<User>,
+
+    /// True, if the bot is allowed to edit administrator privileges of that user
+    pub can_be_edited: bool,
+
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: bool,
+
+    /// True, if the administrator can access the chat event log, chat
+    /// statistics, message statistics in channels, see channel members, see
+    /// anonymous administrators in supergroups and ignore slow mode. Implied by
+    /// any other administrator privilege
+    pub can_manage_chat: bool,
+
+    /// True, if the administrator can delete messages of other users
+    pub can_delete_messages: bool,
+
+    /// True, if the administrator can manage voice chats
+    pub can_manage_voice_chats: bool,
+
+    /// True, if the administrator can restrict, ban or unban chat members
+    pub can_restrict_members: bool,
+
+    /// True, if the administrator can add new administrators with a subset of
+    /// their own privileges or demote administrators that he has promoted,
+    /// directly or indirectly (promoted by administrators that were appointed
+    /// by the user)
+    pub can_promote_members: bool,
+
+    /// True, if the user is allowed to change the chat title, photo and other settings
+    pub can_change_info: bool,
+
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: bool,
+
+    /// True, if the administrator can post in the channel; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+
+    /// True, if the administrator can edit messages of other users and can pin
+    /// messages; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+
+    /// True, if the user is allowed to pin messages; groups and supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+
+    /// Custom title for this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_title: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a chat member that has no additional privileges or restrictions.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberMember {
+    /// Information about the user
+    pub user: Box<User>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a chat member that is under certain restrictions in the chat.
+/// Supergroups only.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberRestricted {
+    /// Information about the user
+    pub user: Box<User>,
+
+    /// True, if the user is a member of the chat at the moment of the request
+    pub is_member: bool,
+
+    /// True, if the user is allowed to change the chat title, photo and other settings
+    pub can_change_info: bool,
+
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: bool,
+
+    /// True, if the user is allowed to pin messages
+    pub can_pin_messages: bool,
+
+    /// True, if the user is allowed to send text messages, contacts, locations
+    /// and venues
+    pub can_send_messages: bool,
+
+    /// True, if the user is allowed to send audios, documents, photos, videos,
+    /// video notes and voice notes
+    pub can_send_media_messages: bool,
+
+    /// True, if the user is allowed to send polls
+    pub can_send_polls: bool,
+
+    /// True, if the user is allowed to send animations, games, stickers and use
+    /// inline bots
+    pub can_send_other_messages: bool,
+
+    /// True, if the user is allowed to add web page previews to their messages
+    pub can_add_web_page_previews: bool,
+
+    /// Date when restrictions will be lifted for this user; unix time. If 0,
+    /// then the user is restricted forever
+    pub until_date: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a chat member that isn't currently a member of the chat, but
+/// may join it themselves.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberLeft {
+    /// Information about the user
+    pub user: Box<User>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a chat member that was banned in the chat and can't return to
+/// the chat or view chat messages.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberBanned {
+    /// Information about the user
+    pub user: Box<User>,
+
+    /// Date when restrictions will be lifted for this user; unix time. If 0,
+    /// then the user is banned forever
+    pub until_date: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents changes in the status of a chat member.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatMemberUpdated {
+    /// Chat the user belongs to
+    pub chat: Box<Chat>,
+
+    /// Performer of the action, which resulted in the change
+    pub from: Box<User>,
+
+    /// Date the change was done in Unix time
+    pub date: i64,
+
+    /// Previous information about the chat member
+    pub old_chat_member: Box<ChatMember>,
+
+    /// New information about the chat member
+    pub new_chat_member: Box<ChatMember>,
+
+    /// Chat invite link, which was used by the user to join the chat; for
+    /// joining by invite link events only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<Box<ChatInviteLink>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Describes actions that a non-administrator user is allowed to take in a chat.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatPermissions {
+    /// True, if the user is allowed to send text messages, contacts, locations
+    /// and venues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_messages: Option<bool>,
+
+    /// True, if the user is allowed to send audios, documents, photos, videos,
+    /// video notes and voice notes, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_media_messages: Option<bool>,
+
+    /// True, if the user is allowed to send polls, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_polls: Option<bool>,
+
+    /// True, if the user is allowed to send animations, games, stickers and use
+    /// inline bots, implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_other_messages: Option<bool>,
+
+    /// True, if the user is allowed to add web page previews to their messages,
+    /// implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_add_web_page_previews: Option<bool>,
+
+    /// True, if the user is allowed to change the chat title, photo and other
+    /// settings. Ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+
+    /// True, if the user is allowed to invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+
+    /// True, if the user is allowed to pin messages. Ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a location to which a chat is connected.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatLocation {
+    /// The location to which the supergroup is connected. Can't be a live location.
+    pub location: Box<Location>,
+
+    /// Location address; 1-64 characters, as defined by the chat owner
+    pub address: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a bot command.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommand {
+    /// Text of the command, 1-32 characters. Can contain only lowercase English
+    /// letters, digits and underscores.
+    pub command: String,
+
+    /// Description of the command, 3-256 characters.
+    pub description: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the default scope of bot commands. Default commands are used
+/// if no commands with a narrower scope are specified for the user.
+/// See https://core.telegram.org/bots/api#botcommandscope
+/// See https://core.telegram.org/bots/api#determining-list-of-commands
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeDefault {
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the scope of bot commands, covering all private chats.
+/// See https://core.telegram.org/bots/api#botcommandscope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeAllPrivateChats {
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the scope of bot commands, covering all group and supergroup chats.
+/// See https://core.telegram.org/bots/api#botcommandscope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeAllGroupChats {
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the scope of bot commands, covering all group and supergroup
+/// chat administrators.
+/// See https://core.telegram.org/bots/api#botcommandscope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeAllChatAdministrators {
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the scope of bot commands, covering a specific chat.
+/// See https://core.telegram.org/bots/api#botcommandscope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeChat {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the scope of bot commands, covering all administrators of a
+/// specific group or supergroup chat.
+/// See https://core.telegram.org/bots/api#botcommandscope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeChatAdministrators {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the scope of bot commands, covering a specific member of a
+/// group or supergroup chat.
+/// See https://core.telegram.org/bots/api#botcommandscope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotCommandScopeChatMember {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Contains information about why a request was unsuccessful.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponseParameters {
+    /// The group has been migrated to a supergroup with the specified
+    /// identifier. This number may have more than 32 significant bits and some
+    /// programming languages may have difficulty/silent defects in interpreting
+    /// it. But it has at most 52 significant bits, so a signed 64-bit integer
+    /// or double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<ChatId>,
+
+    /// In case of exceeding flood control, the number of seconds left to wait
+    /// before the request can be repeated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a photo to be sent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputMediaPhoto {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
+    /// the Internet, or pass “attach://<file_attach_name>” to upload a new one
+    /// using multipart/form-data under <file_attach_name> name. More info on
+    /// Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub media: Box<InputFile>,
+
+    /// Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the photo caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a video to be sent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputMediaVideo {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
+    /// the Internet, or pass “attach://<file_attach_name>” to upload a new one
+    /// using multipart/form-data under <file_attach_name> name. More info on
+    /// Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub media: Box<InputFile>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Caption of the video to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the video caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+
+    /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+
+    /// Video duration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Pass True, if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an animation file (GIF or H.264/MPEG-4 AVC video without
+/// sound) to be sent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputMediaAnimation {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
+    /// the Internet, or pass “attach://<file_attach_name>” to upload a new one
+    /// using multipart/form-data under <file_attach_name> name. More info on
+    /// Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub media: Box<InputFile>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Caption of the animation to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the animation caption. See formatting
+    /// options for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Animation width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+
+    /// Animation height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+
+    /// Animation duration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an audio file to be treated as music to be sent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputMediaAudio {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
+    /// the Internet, or pass “attach://<file_attach_name>” to upload a new one
+    /// using multipart/form-data under <file_attach_name> name. More info on
+    /// Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub media: Box<InputFile>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Caption of the audio to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the audio caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Duration of the audio in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Performer of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+
+    /// Title of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents a general file to be sent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputMediaDocument {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram
+    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
+    /// the Internet, or pass “attach://<file_attach_name>” to upload a new one
+    /// using multipart/form-data under <file_attach_name> name. More info on
+    /// Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub media: Box<InputFile>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Caption of the document to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Disables automatic server-side content type detection for files uploaded
+    /// using multipart/form-data. Always true, if the document is sent as part
+    /// of an album.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_content_type_detection: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Use this method to send text messages. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendMessage {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Text of the message to be sent, 1-4096 characters after entities parsing
+    pub text: String,
+
+    /// Mode for parsing entities in the message text. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in message text, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Disables link previews for links in this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_web_page_preview: Option<bool>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendMessage {
+    const NAME: &'static str = "sendMessage";
+    type Response = Message;
+}
+
+/// Use this method to forward messages of any kind. Service messages can't
+/// be forwarded. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ForwardMessage {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier for the chat where the original message was sent (or
+    /// channel username in the format @channelusername)
+    pub from_chat_id: Recipient,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// Message identifier in the chat specified in from_chat_id
+    pub message_id: MessageIdentifier,
+}
+
+impl Method for ForwardMessage {
+    const NAME: &'static str = "forwardMessage";
+    type Response = Message;
+}
+
+/// Use this method to copy messages of any kind. Service messages and
+/// invoice messages can't be copied. The method is analogous to the method
+/// forwardMessage, but the copied message doesn't have a link to the
+/// original message. Returns the MessageId of the sent message on success.
+/// See https://core.telegram.org/bots/api#forwardmessage
+/// See https://core.telegram.org/bots/api#messageid
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CopyMessage {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier for the chat where the original message was sent (or
+    /// channel username in the format @channelusername)
+    pub from_chat_id: Recipient,
+
+    /// Message identifier in the chat specified in from_chat_id
+    pub message_id: MessageIdentifier,
+
+    /// New caption for media, 0-1024 characters after entities parsing. If not
+    /// specified, the original caption is kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the new caption. See formatting options for
+    /// more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the new caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for CopyMessage {
+    const NAME: &'static str = "copyMessage";
+    type Response = MessageId;
+}
+
+/// Use this method to send photos. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendPhoto {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Photo to send. Pass a file_id as String to send a photo that exists on
+    /// the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a photo from the Internet, or upload a new photo using
+    /// multipart/form-data. The photo must be at most 10 MB in size. The
+    /// photo's width and height must not exceed 10000 in total. Width and
+    /// height ratio must be at most 20. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub photo: Box<InputFile>,
+
+    /// Photo caption (may also be used when resending photos by file_id),
+    /// 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the photo caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendPhoto {
+    const NAME: &'static str = "sendPhoto";
+    type Response = Message;
+}
+
+/// Use this method to send audio files, if you want Telegram clients to
+/// display them in the music player. Your audio must be in the .MP3 or .M4A
+/// format. On success, the sent Message is returned. Bots can currently
+/// send audio files of up to 50 MB in size, this limit may be changed in
+/// the future.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendAudio {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Audio file to send. Pass a file_id as String to send an audio file that
+    /// exists on the Telegram servers (recommended), pass an HTTP URL as a
+    /// String for Telegram to get an audio file from the Internet, or upload a
+    /// new one using multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub audio: Box<InputFile>,
+
+    /// Audio caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the audio caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Duration of the audio in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Performer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+
+    /// Track name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendAudio {
+    const NAME: &'static str = "sendAudio";
+    type Response = Message;
+}
+
+/// Use this method to send general files. On success, the sent Message is
+/// returned. Bots can currently send files of any type of up to 50 MB in
+/// size, this limit may be changed in the future.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendDocument {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// File to send. Pass a file_id as String to send a file that exists on the
+    /// Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, or upload a new one using
+    /// multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub document: Box<InputFile>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Document caption (may also be used when resending documents by file_id),
+    /// 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Disables automatic server-side content type detection for files uploaded
+    /// using multipart/form-data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_content_type_detection: Option<bool>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendDocument {
+    const NAME: &'static str = "sendDocument";
+    type Response = Message;
+}
+
+/// Use this method to send video files, Telegram clients support mp4 videos
+/// (other formats may be sent as Document). On success, the sent Message is
+/// returned. Bots can currently send video files of up to 50 MB in size,
+/// this limit may be changed in the future.
+/// See https://core.telegram.org/bots/api#document
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendVideo {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Video to send. Pass a file_id as String to send a video that exists on
+    /// the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a video from the Internet, or upload a new video using
+    /// multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub video: Box<InputFile>,
+
+    /// Duration of sent video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+
+    /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Video caption (may also be used when resending videos by file_id),
+    /// 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the video caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Pass True, if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendVideo {
+    const NAME: &'static str = "sendVideo";
+    type Response = Message;
+}
+
+/// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video
+/// without sound). On success, the sent Message is returned. Bots can
+/// currently send animation files of up to 50 MB in size, this limit may be
+/// changed in the future.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendAnimation {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Animation to send. Pass a file_id as String to send an animation that
+    /// exists on the Telegram servers (recommended), pass an HTTP URL as a
+    /// String for Telegram to get an animation from the Internet, or upload a
+    /// new animation using multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub animation: Box<InputFile>,
+
+    /// Duration of sent animation in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Animation width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+
+    /// Animation height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Animation caption (may also be used when resending animation by
+    /// file_id), 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the animation caption. See formatting
+    /// options for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendAnimation {
+    const NAME: &'static str = "sendAnimation";
+    type Response = Message;
+}
+
+/// Use this method to send audio files, if you want Telegram clients to
+/// display the file as a playable voice message. For this to work, your
+/// audio must be in an .OGG file encoded with OPUS (other formats may be
+/// sent as Audio or Document). On success, the sent Message is returned.
+/// Bots can currently send voice messages of up to 50 MB in size, this
+/// limit may be changed in the future.
+/// See https://core.telegram.org/bots/api#audio
+/// See https://core.telegram.org/bots/api#document
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendVoice {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Audio file to send. Pass a file_id as String to send a file that exists
+    /// on the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, or upload a new one using
+    /// multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub voice: Box<InputFile>,
+
+    /// Voice message caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Mode for parsing entities in the voice message caption. See formatting
+    /// options for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Duration of the voice message in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendVoice {
+    const NAME: &'static str = "sendVoice";
+    type Response = Message;
+}
+
+/// As of v.4.0, Telegram clients support rounded square mp4 videos of up to
+/// 1 minute long. Use this method to send video messages. On success, the
+/// sent Message is returned.
+/// See https://telegram.org/blog/video-messages-and-telescope
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendVideoNote {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Video note to send. Pass a file_id as String to send a video note that
+    /// exists on the Telegram servers (recommended) or upload a new video using
+    /// multipart/form-data. More info on Sending Files ». Sending video notes
+    /// by a URL is currently unsupported
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub video_note: Box<InputFile>,
+
+    /// Duration of sent video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Video width and height, i.e. diameter of the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<i64>,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail's width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data. Thumbnails can't be reused and can be only uploaded
+    /// as a new file, so you can pass “attach://<file_attach_name>” if the
+    /// thumbnail was uploaded using multipart/form-data under
+    /// <file_attach_name>. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendVideoNote {
+    const NAME: &'static str = "sendVideoNote";
+    type Response = Message;
+}
+
+/// Use this method to send a group of photos, videos, documents or audios
+/// as an album. Documents and audio files can be only grouped in an album
+/// with messages of the same type. On success, an array of Messages that
+/// were sent is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendMediaGroup {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// A JSON-serialized array describing messages to be sent, must include
+    /// 2-10 items
+    pub media: Vec<Box<InputMedia>>,
+
+    /// Sends messages silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the messages are a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+}
+
+impl Method for SendMediaGroup {
+    const NAME: &'static str = "sendMediaGroup";
+    type Response = Vec<Message>;
+}
+
+/// Use this method to send point on the map. On success, the sent Message
+/// is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendLocation {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Latitude of the location
+    pub latitude: f64,
+
+    /// Longitude of the location
+    pub longitude: f64,
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Period in seconds for which the location will be updated (see Live
+    /// Locations, should be between 60 and 86400.
+    /// See https://telegram.org/blog/live-locations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i64>,
+
+    /// For live locations, a direction in which the user is moving, in degrees.
+    /// Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+
+    /// For live locations, a maximum distance for proximity alerts about
+    /// approaching another chat member, in meters. Must be between 1 and 100000
+    /// if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendLocation {
+    const NAME: &'static str = "sendLocation";
+    type Response = Message;
+}
+
+/// Use this method to edit live location messages. A location can be edited
+/// until its live_period expires or editing is explicitly disabled by a
+/// call to stopMessageLiveLocation. On success, if the edited message is
+/// not an inline message, the edited Message is returned, otherwise True is returned.
+/// See https://core.telegram.org/bots/api#stopmessagelivelocation
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditMessageLiveLocation {
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Recipient>,
+
+    /// Required if inline_message_id is not specified. Identifier of the
+    /// message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+
+    /// Latitude of new location
+    pub latitude: f64,
+
+    /// Longitude of new location
+    pub longitude: f64,
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Direction in which the user is moving, in degrees. Must be between 1 and
+    /// 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+
+    /// Maximum distance for proximity alerts about approaching another chat
+    /// member, in meters. Must be between 1 and 100000 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+
+    /// A JSON-serialized object for a new inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
+
+impl Method for EditMessageLiveLocation {
+    const NAME: &'static str = "editMessageLiveLocation";
+    type Response = MessageOrBool;
+}
+
+/// Use this method to stop updating a live location message before
+/// live_period expires. On success, if the message is not an inline
+/// message, the edited Message is returned, otherwise True is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StopMessageLiveLocation {
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Recipient>,
+
+    /// Required if inline_message_id is not specified. Identifier of the
+    /// message with live location to stop
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+
+    /// A JSON-serialized object for a new inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
+
+impl Method for StopMessageLiveLocation {
+    const NAME: &'static str = "stopMessageLiveLocation";
+    type Response = MessageOrBool;
+}
+
+/// Use this method to send information about a venue. On success, the sent
+/// Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendVenue {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Latitude of the venue
+    pub latitude: f64,
+
+    /// Longitude of the venue
+    pub longitude: f64,
+
+    /// Name of the venue
+    pub title: String,
+
+    /// Address of the venue
+    pub address: String,
+
+    /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+
+    /// Foursquare type of the venue, if known. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+
+    /// Google Places type of the venue. (See supported types.)
+    /// See https://developers.google.com/places/web-service/supported_types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendVenue {
+    const NAME: &'static str = "sendVenue";
+    type Response = Message;
+}
+
+/// Use this method to send phone contacts. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendContact {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Contact's phone number
+    pub phone_number: String,
+
+    /// Contact's first name
+    pub first_name: String,
+
+    /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    /// See https://en.wikipedia.org/wiki/VCard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove keyboard or to
+    /// force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendContact {
+    const NAME: &'static str = "sendContact";
+    type Response = Message;
+}
+
+/// Use this method to send a native poll. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendPoll {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Poll question, 1-300 characters
+    pub question: String,
+
+    /// A JSON-serialized list of answer options, 2-10 strings 1-100 characters each
+    pub options: Vec<String>,
+
+    /// True, if the poll needs to be anonymous, defaults to True
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+
+    /// Poll type, “quiz” or “regular”, defaults to “regular”
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_tl: Option<String>,
+
+    /// True, if the poll allows multiple answers, ignored for polls in quiz
+    /// mode, defaults to False
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allows_multiple_answers: Option<bool>,
+
+    /// 0-based identifier of the correct answer option, required for polls in
+    /// quiz mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<i64>,
+
+    /// Text that is shown when a user chooses an incorrect answer or taps on
+    /// the lamp icon in a quiz-style poll, 0-200 characters with at most 2 line
+    /// feeds after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+
+    /// Mode for parsing entities in the explanation. See formatting options for
+    /// more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the poll explanation, which can
+    /// be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_entities: Option<Vec<Box<MessageEntity>>>,
+
+    /// Amount of time in seconds the poll will be active after creation, 5-600.
+    /// Can't be used together with close_date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i64>,
+
+    /// Point in time (Unix timestamp) when the poll will be automatically
+    /// closed. Must be at least 5 and no more than 600 seconds in the future.
+    /// Can't be used together with open_period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i64>,
+
+    /// Pass True, if the poll needs to be immediately closed. This can be
+    /// useful for poll preview.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_closed: Option<bool>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendPoll {
+    const NAME: &'static str = "sendPoll";
+    type Response = Message;
+}
+
+/// Use this method to send an animated emoji that will display a random
+/// value. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendDice {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Emoji on which the dice throw animation is based. Currently, must be one
+    /// of “”, “”, “”, “”, “”, or “”. Dice can have values 1-6 for “”, “” and
+    /// “”, values 1-5 for “” and “”, and values 1-64 for “”. Defaults to “”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendDice {
+    const NAME: &'static str = "sendDice";
+    type Response = Message;
+}
+
+/// Use this method when you need to tell the user that something is
+/// happening on the bot's side. The status is set for 5 seconds or less
+/// (when a message arrives from your bot, Telegram clients clear its typing
+/// status). Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendChatAction {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Type of action to broadcast. Choose one, depending on what the user is
+    /// about to receive: typing for text messages, upload_photo for photos,
+    /// record_video or upload_video for videos, record_voice or upload_voice
+    /// for voice notes, upload_document for general files, find_location for
+    /// location data, record_video_note or upload_video_note for video notes.
+    /// See https://core.telegram.org/bots/api#sendmessage
+    /// See https://core.telegram.org/bots/api#sendphoto
+    /// See https://core.telegram.org/bots/api#sendvideo
+    /// See https://core.telegram.org/bots/api#sendvoice
+    /// See https://core.telegram.org/bots/api#senddocument
+    /// See https://core.telegram.org/bots/api#sendlocation
+    /// See https://core.telegram.org/bots/api#sendvideonote
+    pub action: String,
+}
+
+impl Method for SendChatAction {
+    const NAME: &'static str = "sendChatAction";
+    type Response = bool;
+}
+
+/// Use this method to get a list of profile pictures for a user. Returns a
+/// UserProfilePhotos object.
+/// See https://core.telegram.org/bots/api#userprofilephotos
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetUserProfilePhotos {
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// Sequential number of the first photo to be returned. By default, all
+    /// photos are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /// Limits the number of photos to be retrieved. Values between 1-100 are
+    /// accepted. Defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+impl Method for GetUserProfilePhotos {
+    const NAME: &'static str = "getUserProfilePhotos";
+    type Response = UserProfilePhotos;
+}
+
+/// Use this method to get basic info about a file and prepare it for
+/// downloading. For the moment, bots can download files of up to 20MB in
+/// size. On success, a File object is returned. The file can then be
+/// downloaded via the link
+/// https://api.telegram.org/file/bot<token>/<file_path>, where <file_path>
+/// is taken from the response. It is guaranteed that the link will be valid
+/// for at least 1 hour. When the link expires, a new one can be requested
+/// by calling getFile again.
+/// See https://core.telegram.org/bots/api#file
+/// See https://core.telegram.org/bots/api#getfile
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetFile {
+    /// File identifier to get info about
+    pub file_id: FileId,
+}
+
+impl Method for GetFile {
+    const NAME: &'static str = "getFile";
+    type Response = File;
+}
+
+/// Use this method to ban a user in a group, a supergroup or a channel. In
+/// the case of supergroups and channels, the user will not be able to
+/// return to the chat on their own using invite links, etc., unless
+/// unbanned first. The bot must be an administrator in the chat for this to
+/// work and must have the appropriate admin rights. Returns True on success.
+/// See https://core.telegram.org/bots/api#unbanchatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BanChatMember {
+    /// Unique identifier for the target group or username of the target
+    /// supergroup or channel (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// Date when the user will be unbanned, unix time. If user is banned for
+    /// more than 366 days or less than 30 seconds from the current time they
+    /// are considered to be banned forever. Applied for supergroups and
+    /// channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+
+    /// Pass True to delete all messages from the chat for the user that is
+    /// being removed. If False, the user will be able to see messages in the
+    /// group that were sent before the user was removed. Always True for
+    /// supergroups and channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_messages: Option<bool>,
+}
+
+impl Method for BanChatMember {
+    const NAME: &'static str = "banChatMember";
+    type Response = bool;
+}
+
+/// Use this method to unban a previously banned user in a supergroup or
+/// channel. The user will not return to the group or channel automatically,
+/// but will be able to join via link, etc. The bot must be an administrator
+/// for this to work. By default, this method guarantees that after the call
+/// the user is not a member of the chat, but will be able to join it. So if
+/// the user is a member of the chat they will also be removed from the
+/// chat. If you don't want this, use the parameter only_if_banned. Returns
+/// True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnbanChatMember {
+    /// Unique identifier for the target group or username of the target
+    /// supergroup or channel (in the format @username)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// Do nothing if the user is not banned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_if_banned: Option<bool>,
+}
+
+impl Method for UnbanChatMember {
+    const NAME: &'static str = "unbanChatMember";
+    type Response = bool;
+}
+
+/// Use this method to restrict a user in a supergroup. The bot must be an
+/// administrator in the supergroup for this to work and must have the
+/// appropriate admin rights. Pass True for all permissions to lift
+/// restrictions from a user. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RestrictChatMember {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// A JSON-serialized object for new user permissions
+    pub permissions: Box<ChatPermissions>,
+
+    /// Date when restrictions will be lifted for the user, unix time. If user
+    /// is restricted for more than 366 days or less than 30 seconds from the
+    /// current time, they are considered to be restricted forever
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+}
+
+impl Method for RestrictChatMember {
+    const NAME: &'static str = "restrictChatMember";
+    type Response = bool;
+}
+
+/// Use this method to promote or demote a user in a supergroup or a
+/// channel. The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights. Pass False for all boolean
+/// parameters to demote a user. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PromoteChatMember {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// Pass True, if the administrator's presence in the chat is hidden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+
+    /// Pass True, if the administrator can access the chat event log, chat
+    /// statistics, message statistics in channels, see channel members, see
+    /// anonymous administrators in supergroups and ignore slow mode. Implied by
+    /// any other administrator privilege
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_chat: Option<bool>,
+
+    /// Pass True, if the administrator can create channel posts, channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+
+    /// Pass True, if the administrator can edit messages of other users and can
+    /// pin messages, channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+
+    /// Pass True, if the administrator can delete messages of other users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_messages: Option<bool>,
+
+    /// Pass True, if the administrator can manage voice chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_voice_chats: Option<bool>,
+
+    /// Pass True, if the administrator can restrict, ban or unban chat members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_restrict_members: Option<bool>,
+
+    /// Pass True, if the administrator can add new administrators with a subset
+    /// of their own privileges or demote administrators that he has promoted,
+    /// directly or indirectly (promoted by administrators that were appointed
+    /// by him)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_promote_members: Option<bool>,
+
+    /// Pass True, if the administrator can change chat title, photo and other settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+
+    /// Pass True, if the administrator can invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+
+    /// Pass True, if the administrator can pin messages, supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+}
+
+impl Method for PromoteChatMember {
+    const NAME: &'static str = "promoteChatMember";
+    type Response = bool;
+}
+
+/// Use this method to set a custom title for an administrator in a
+/// supergroup promoted by the bot. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetChatAdministratorCustomTitle {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+
+    /// New custom title for the administrator; 0-16 characters, emoji are not allowed
+    pub custom_title: String,
+}
+
+impl Method for SetChatAdministratorCustomTitle {
+    const NAME: &'static str = "setChatAdministratorCustomTitle";
+    type Response = bool;
+}
+
+/// Use this method to set default chat permissions for all members. The bot
+/// must be an administrator in the group or a supergroup for this to work
+/// and must have the can_restrict_members admin rights. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetChatPermissions {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// New default chat permissions
+    pub permissions: Box<ChatPermissions>,
+}
+
+impl Method for SetChatPermissions {
+    const NAME: &'static str = "setChatPermissions";
+    type Response = bool;
+}
+
+/// Use this method to generate a new primary invite link for a chat; any
+/// previously generated primary link is revoked. The bot must be an
+/// administrator in the chat for this to work and must have the appropriate
+/// admin rights. Returns the new invite link as String on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExportChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for ExportChatInviteLink {
+    const NAME: &'static str = "exportChatInviteLink";
+    type Response = String;
+}
+
+/// Use this method to create an additional invite link for a chat. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// appropriate admin rights. The link can be revoked using the method
+/// revokeChatInviteLink. Returns the new invite link as ChatInviteLink object.
+/// See https://core.telegram.org/bots/api#revokechatinvitelink
+/// See https://core.telegram.org/bots/api#chatinvitelink
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+
+    /// Maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+}
+
+impl Method for CreateChatInviteLink {
+    const NAME: &'static str = "createChatInviteLink";
+    type Response = ChatInviteLink;
+}
+
+/// Use this method to edit a non-primary invite link created by the bot.
+/// The bot must be an administrator in the chat for this to work and must
+/// have the appropriate admin rights. Returns the edited invite link as a
+/// ChatInviteLink object.
+/// See https://core.telegram.org/bots/api#chatinvitelink
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditChatInviteLink {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// The invite link to edit
+    pub invite_link: String,
+
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+
+    /// Maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i64>,
+}
+
+impl Method for EditChatInviteLink {
+    const NAME: &'static str = "editChatInviteLink";
+    type Response = ChatInviteLink;
+}
+
+/// Use this method to revoke an invite link created by the bot. If the
+/// primary link is revoked, a new link is automatically generated. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// appropriate admin rights. Returns the revoked invite link as
+/// ChatInviteLink object.
+/// See https://core.telegram.org/bots/api#chatinvitelink
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevokeChatInviteLink {
+    /// Unique identifier of the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// The invite link to revoke
+    pub invite_link: String,
+}
+
+impl Method for RevokeChatInviteLink {
+    const NAME: &'static str = "revokeChatInviteLink";
+    type Response = ChatInviteLink;
+}
+
+/// Use this method to set a new profile photo for the chat. Photos can't be
+/// changed for private chats. The bot must be an administrator in the chat
+/// for this to work and must have the appropriate admin rights. Returns
+/// True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetChatPhoto {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// New chat photo, uploaded using multipart/form-data
+    pub photo: Box<InputFile>,
+}
+
+impl Method for SetChatPhoto {
+    const NAME: &'static str = "setChatPhoto";
+    type Response = bool;
+}
+
+/// Use this method to delete a chat photo. Photos can't be changed for
+/// private chats. The bot must be an administrator in the chat for this to
+/// work and must have the appropriate admin rights. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteChatPhoto {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for DeleteChatPhoto {
+    const NAME: &'static str = "deleteChatPhoto";
+    type Response = bool;
+}
+
+/// Use this method to change the title of a chat. Titles can't be changed
+/// for private chats. The bot must be an administrator in the chat for this
+/// to work and must have the appropriate admin rights. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetChatTitle {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// New chat title, 1-255 characters
+    pub title: String,
+}
+
+impl Method for SetChatTitle {
+    const NAME: &'static str = "setChatTitle";
+    type Response = bool;
+}
+
+/// Use this method to change the description of a group, a supergroup or a
+/// channel. The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetChatDescription {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// New chat description, 0-255 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Method for SetChatDescription {
+    const NAME: &'static str = "setChatDescription";
+    type Response = bool;
+}
+
+/// Use this method to add a message to the list of pinned messages in a
+/// chat. If the chat is not a private chat, the bot must be an
+/// administrator in the chat for this to work and must have the
+/// 'can_pin_messages' admin right in a supergroup or 'can_edit_messages'
+/// admin right in a channel. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PinChatMessage {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Identifier of a message to pin
+    pub message_id: MessageIdentifier,
+
+    /// Pass True, if it is not necessary to send a notification to all chat
+    /// members about the new pinned message. Notifications are always disabled
+    /// in channels and private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+}
+
+impl Method for PinChatMessage {
+    const NAME: &'static str = "pinChatMessage";
+    type Response = bool;
+}
+
+/// Use this method to remove a message from the list of pinned messages in
+/// a chat. If the chat is not a private chat, the bot must be an
+/// administrator in the chat for this to work and must have the
+/// 'can_pin_messages' admin right in a supergroup or 'can_edit_messages'
+/// admin right in a channel. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnpinChatMessage {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Identifier of a message to unpin. If not specified, the most recent
+    /// pinned message (by sending date) will be unpinned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+}
+
+impl Method for UnpinChatMessage {
+    const NAME: &'static str = "unpinChatMessage";
+    type Response = bool;
+}
+
+/// Use this method to clear the list of pinned messages in a chat. If the
+/// chat is not a private chat, the bot must be an administrator in the chat
+/// for this to work and must have the 'can_pin_messages' admin right in a
+/// supergroup or 'can_edit_messages' admin right in a channel. Returns True
+/// on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnpinAllChatMessages {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for UnpinAllChatMessages {
+    const NAME: &'static str = "unpinAllChatMessages";
+    type Response = bool;
+}
+
+/// Use this method for your bot to leave a group, supergroup or channel.
+/// Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeaveChat {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup or channel (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for LeaveChat {
+    const NAME: &'static str = "leaveChat";
+    type Response = bool;
+}
+
+/// Use this method to get up to date information about the chat (current
+/// name of the user for one-on-one conversations, current username of a
+/// user, group or channel, etc.). Returns a Chat object on success.
+/// See https://core.telegram.org/bots/api#chat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetChat {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup or channel (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for GetChat {
+    const NAME: &'static str = "getChat";
+    type Response = Chat;
+}
+
+/// Use this method to get a list of administrators in a chat. On success,
+/// returns an Array of ChatMember objects that contains information about
+/// all chat administrators except other bots. If the chat is a group or a
+/// supergroup and no administrators were appointed, only the creator will
+/// be returned.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetChatAdministrators {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup or channel (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for GetChatAdministrators {
+    const NAME: &'static str = "getChatAdministrators";
+    type Response = Vec<ChatMember>;
+}
+
+/// Use this method to get the number of members in a chat. Returns Int on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetChatMemberCount {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup or channel (in the format @channelusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for GetChatMemberCount {
+    const NAME: &'static str = "getChatMemberCount";
+    type Response = i64;
+}
+
+/// Use this method to get information about a member of a chat. Returns a
+/// ChatMember object on success.
+/// See https://core.telegram.org/bots/api#chatmember
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetChatMember {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup or channel (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Unique identifier of the target user
+    pub user_id: UserId,
+}
+
+impl Method for GetChatMember {
+    const NAME: &'static str = "getChatMember";
+    type Response = ChatMember;
+}
+
+/// Use this method to set a new group sticker set for a supergroup. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// appropriate admin rights. Use the field can_set_sticker_set optionally
+/// returned in getChat requests to check if the bot can use this method.
+/// Returns True on success.
+/// See https://core.telegram.org/bots/api#getchat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetChatStickerSet {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+
+    /// Name of the sticker set to be set as the group sticker set
+    pub sticker_set_name: String,
+}
+
+impl Method for SetChatStickerSet {
+    const NAME: &'static str = "setChatStickerSet";
+    type Response = bool;
+}
+
+/// Use this method to delete a group sticker set from a supergroup. The bot
+/// must be an administrator in the chat for this to work and must have the
+/// appropriate admin rights. Use the field can_set_sticker_set optionally
+/// returned in getChat requests to check if the bot can use this method.
+/// Returns True on success.
+/// See https://core.telegram.org/bots/api#getchat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteChatStickerSet {
+    /// Unique identifier for the target chat or username of the target
+    /// supergroup (in the format @supergroupusername)
+    pub chat_id: Recipient,
+}
+
+impl Method for DeleteChatStickerSet {
+    const NAME: &'static str = "deleteChatStickerSet";
+    type Response = bool;
+}
+
+/// Use this method to send answers to callback queries sent from inline
+/// keyboards. The answer will be displayed to the user as a notification at
+/// the top of the chat screen or as an alert. On success, True is returned.
+/// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerCallbackQuery {
+    /// Unique identifier for the query to be answered
+    pub callback_query_id: CallbackQueryId,
+
+    /// Text of the notification. If not specified, nothing will be shown to the
+    /// user, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// If true, an alert will be shown by the client instead of a notification
+    /// at the top of the chat screen. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_alert: Option<bool>,
+
+    /// URL that will be opened by the user's client. If you have created a Game
+    /// and accepted the conditions via @Botfather, specify the URL that opens
+    /// your game — note that this will only work if the query comes from a
+    /// callback_game button.Otherwise, you may use links like
+    /// t.me/your_bot?start=XXXX that open your bot with a parameter.
+    /// See https://core.telegram.org/bots/api#game
+    /// See https://t.me/botfather
+    /// See https://core.telegram.org/bots/api#inlinekeyboardbutton
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The maximum amount of time in seconds that the result of the callback
+    /// query may be cached client-side. Telegram apps will support caching
+    /// starting in version 3.14. Defaults to 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i64>,
+}
+
+impl Method for AnswerCallbackQuery {
+    const NAME: &'static str = "answerCallbackQuery";
+    type Response = bool;
 }
 
-/// This object represents a chat photo.
+/// Use this method to change the list of the bot's commands. See
+/// https://core.telegram.org/bots#commands for more details about bot
+/// commands. Returns True on success.
+/// See https://core.telegram.org/bots#commands
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ChatPhoto {
-    /// Unique file identifier of small (160x160) chat photo. This file_id can
-    /// be used only for photo download.
-    pub small_file_id: String,
+pub struct SetMyCommands {
+    /// A JSON-serialized list of bot commands to be set as the list of the
+    /// bot's commands. At most 100 commands can be specified.
+    pub commands: Vec<Box<BotCommand>>,
+
+    /// A JSON-serialized object, describing scope of users for which the
+    /// commands are relevant. Defaults to BotCommandScopeDefault.
+    /// See https://core.telegram.org/bots/api#botcommandscopedefault
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Box<BotCommandScope>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied
+    /// to all users from the given scope, for whose language there are no
+    /// dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
 
-    /// Unique file identifier of big (640x640) chat photo. This file_id can be
-    /// used only for photo download.
-    pub big_file_id: String,
+impl Method for SetMyCommands {
+    const NAME: &'static str = "setMyCommands";
+    type Response = bool;
 }
 
-/// This object contains information about one member of a chat.
+/// Use this method to delete the list of the bot's commands for the given
+/// scope and user language. After deletion, higher level commands will be
+/// shown to affected users. Returns True on success.
+/// See https://core.telegram.org/bots/api#determining-list-of-commands
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ChatMember {
-    /// Information about the user
-    pub user: Box<User>,
-
-    /// The member's status in the chat. Can be “creator”, “administrator”,
-    /// “member”, “restricted”, “left” or “kicked”
-    pub status: String,
+pub struct DeleteMyCommands {
+    /// A JSON-serialized object, describing scope of users for which the
+    /// commands are relevant. Defaults to BotCommandScopeDefault.
+    /// See https://core.telegram.org/bots/api#botcommandscopedefault
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Box<BotCommandScope>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied
+    /// to all users from the given scope, for whose language there are no
+    /// dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
 
-    /// Restricted and kicked only. Date when restrictions will be lifted for
-    /// this user, unix time
-    pub until_date: Option<i64>,
+impl Method for DeleteMyCommands {
+    const NAME: &'static str = "deleteMyCommands";
+    type Response = bool;
+}
 
-    /// Administrators only. True, if the bot is allowed to edit administrator
-    /// privileges of that user
-    pub can_be_edited: Option<bool>,
+/// Use this method to get the current list of the bot's commands for the
+/// given scope and user language. Returns Array of BotCommand on success.
+/// If commands aren't set, an empty list is returned.
+/// See https://core.telegram.org/bots/api#botcommand
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetMyCommands {
+    /// A JSON-serialized object, describing scope of users. Defaults to BotCommandScopeDefault.
+    /// See https://core.telegram.org/bots/api#botcommandscopedefault
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Box<BotCommandScope>>,
+
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
 
-    /// Administrators only. True, if the administrator can change the chat
-    /// title, photo and other settings
-    pub can_change_info: Option<bool>,
+impl Method for GetMyCommands {
+    const NAME: &'static str = "getMyCommands";
+    type Response = Vec<BotCommand>;
+}
 
-    /// Administrators only. True, if the administrator can post in the channel,
-    /// channels only
-    pub can_post_messages: Option<bool>,
+/// Use this method to edit text and game messages. On success, if the
+/// edited message is not an inline message, the edited Message is returned,
+/// otherwise True is returned.
+/// See https://core.telegram.org/bots/api#games
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditMessageText {
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Recipient>,
+
+    /// Required if inline_message_id is not specified. Identifier of the
+    /// message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+
+    /// New text of the message, 1-4096 characters after entities parsing
+    pub text: String,
 
-    /// Administrators only. True, if the administrator can edit messages of
-    /// other users and can pin messages, channels only
-    pub can_edit_messages: Option<bool>,
+    /// Mode for parsing entities in the message text. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
 
-    /// Administrators only. True, if the administrator can delete messages of
-    /// other users
-    pub can_delete_messages: Option<bool>,
+    /// List of special entities that appear in message text, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Box<MessageEntity>>>,
 
-    /// Administrators only. True, if the administrator can invite new users to
-    /// the chat
-    pub can_invite_users: Option<bool>,
+    /// Disables link previews for links in this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_web_page_preview: Option<bool>,
 
-    /// Administrators only. True, if the administrator can restrict, ban or
-    /// unban chat members
-    pub can_restrict_members: Option<bool>,
+    /// A JSON-serialized object for an inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
 
-    /// Administrators only. True, if the administrator can pin messages,
-    /// supergroups only
-    pub can_pin_messages: Option<bool>,
+impl Method for EditMessageText {
+    const NAME: &'static str = "editMessageText";
+    type Response = MessageOrBool;
+}
 
-    /// Administrators only. True, if the administrator can add new
-    /// administrators with a subset of his own privileges or demote
-    /// administrators that he has promoted, directly or indirectly (promoted by
-    /// administrators that were appointed by the user)
-    pub can_promote_members: Option<bool>,
+/// Use this method to edit captions of messages. On success, if the edited
+/// message is not an inline message, the edited Message is returned,
+/// otherwise True is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditMessageCaption {
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Recipient>,
+
+    /// Required if inline_message_id is not specified. Identifier of the
+    /// message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+
+    /// New caption of the message, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
 
-    /// Restricted only. True, if the user can send text messages, contacts,
-    /// locations and venues
-    pub can_send_messages: Option<bool>,
+    /// Mode for parsing entities in the message caption. See formatting options
+    /// for more details.
+    /// See https://core.telegram.org/bots/api#formatting-options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
 
-    /// Restricted only. True, if the user can send audios, documents, photos,
-    /// videos, video notes and voice notes, implies can_send_messages
-    pub can_send_media_messages: Option<bool>,
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
-    /// Restricted only. True, if the user can send animations, games, stickers
-    /// and use inline bots, implies can_send_media_messages
-    pub can_send_other_messages: Option<bool>,
+    /// A JSON-serialized object for an inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
 
-    /// Restricted only. True, if user may add web page previews to his
-    /// messages, implies can_send_media_messages
-    pub can_add_web_page_previews: Option<bool>,
+impl Method for EditMessageCaption {
+    const NAME: &'static str = "editMessageCaption";
+    type Response = MessageOrBool;
 }
 
-/// Contains information about why a request was unsuccessful.
+/// Use this method to edit animation, audio, document, photo, or video
+/// messages. If a message is part of a message album, then it can be edited
+/// only to an audio for audio albums, only to a document for document
+/// albums and to a photo or a video otherwise. When an inline message is
+/// edited, a new file can't be uploaded; use a previously uploaded file via
+/// its file_id or specify a URL. On success, if the edited message is not
+/// an inline message, the edited Message is returned, otherwise True is returned.
+/// See https://core.telegram.org/bots/api#message
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ResponseParameters {
-    /// The group has been migrated to a supergroup with the specified
-    /// identifier. This number may be greater than 32 bits and some programming
-    /// languages may have difficulty/silent defects in interpreting it. But it
-    /// is smaller than 52 bits, so a signed 64 bit integer or double-precision
-    /// float type are safe for storing this identifier.
-    pub migrate_to_chat_id: Option<i64>,
+pub struct EditMessageMedia {
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Recipient>,
+
+    /// Required if inline_message_id is not specified. Identifier of the
+    /// message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+
+    /// A JSON-serialized object for a new media content of the message
+    pub media: Box<InputMedia>,
+
+    /// A JSON-serialized object for a new inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
 
-    /// In case of exceeding flood control, the number of seconds left to wait
-    /// before the request can be repeated
-    pub retry_after: Option<i64>,
+impl Method for EditMessageMedia {
+    const NAME: &'static str = "editMessageMedia";
+    type Response = MessageOrBool;
 }
 
-/// Represents a photo to be sent.
+/// Use this method to edit only the reply markup of messages. On success,
+/// if the edited message is not an inline message, the edited Message is
+/// returned, otherwise True is returned.
+/// See https://core.telegram.org/bots/api#message
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct InputMediaPhoto {
-    /// Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
-    /// File to send. Pass a file_id to send a file that exists on the Telegram
-    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
-    /// the Internet, or pass "attach://<file_attach_name>" to upload a new one
-    /// using multipart/form-data under <file_attach_name> name. More info on
-    /// Sending Files »
-    /// See https://core.telegram.org/bots/api#sending-files
-    pub media: String,
-
-    /// Caption of the photo to be sent, 0-200 characters
-    pub caption: Option<String>,
+pub struct EditMessageReplyMarkup {
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Recipient>,
+
+    /// Required if inline_message_id is not specified. Identifier of the
+    /// message to edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+
+    /// A JSON-serialized object for an inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
-    /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+impl Method for EditMessageReplyMarkup {
+    const NAME: &'static str = "editMessageReplyMarkup";
+    type Response = MessageOrBool;
 }
 
-/// Represents a video to be sent.
+/// Use this method to stop a poll which was sent by the bot. On success,
+/// the stopped Poll is returned.
+/// See https://core.telegram.org/bots/api#poll
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct InputMediaVideo {
-    /// Type of the result, must be video
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
-    /// File to send. Pass a file_id to send a file that exists on the Telegram
-    /// servers (recommended), pass an HTTP URL for Telegram to get a file from
-    /// the Internet, or pass "attach://<file_attach_name>" to upload a new one
-    /// using multipart/form-data under <file_attach_name> name. More info on
-    /// Sending Files »
-    /// See https://core.telegram.org/bots/api#sending-files
-    pub media: String,
+pub struct StopPoll {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
 
-    /// Caption of the video to be sent, 0-200 characters
-    pub caption: Option<String>,
+    /// Identifier of the original message with the poll
+    pub message_id: MessageIdentifier,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
-    /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    /// A JSON-serialized object for a new message inline keyboard.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
 
-    /// Video width
-    pub width: Option<i64>,
+impl Method for StopPoll {
+    const NAME: &'static str = "stopPoll";
+    type Response = Poll;
+}
 
-    /// Video height
-    pub height: Option<i64>,
+/// Use this method to delete a message, including service messages, with
+/// the following limitations:- A message can only be deleted if it was sent
+/// less than 48 hours ago.- A dice message in a private chat can only be
+/// deleted if it was sent more than 24 hours ago.- Bots can delete outgoing
+/// messages in private chats, groups, and supergroups.- Bots can delete
+/// incoming messages in private chats.- Bots granted can_post_messages
+/// permissions can delete outgoing messages in channels.- If the bot is an
+/// administrator of a group, it can delete any message there.- If the bot
+/// has can_delete_messages permission in a supergroup or a channel, it can
+/// delete any message there.Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteMessage {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
 
-    /// Video duration
-    pub duration: Option<i64>,
+    /// Identifier of the message to delete
+    pub message_id: MessageIdentifier,
+}
 
-    /// Pass True, if the uploaded video is suitable for streaming
-    pub supports_streaming: Option<bool>,
+impl Method for DeleteMessage {
+    const NAME: &'static str = "deleteMessage";
+    type Response = bool;
 }
 
 /// This object represents a sticker.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Sticker {
-    /// Unique identifier for this file
-    pub file_id: String,
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
 
     /// Sticker width
     pub width: i64,
@@ -950,20 +4731,35 @@ pub struct Sticker {
     /// Sticker height
     pub height: i64,
 
-    /// Sticker thumbnail in the .webp or .jpg format
+    /// True, if the sticker is animated
+    /// See https://telegram.org/blog/animated-stickers
+    pub is_animated: bool,
+
+    /// Sticker thumbnail in the .WEBP or .JPG format
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<Box<PhotoSize>>,
 
     /// Emoji associated with the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji: Option<String>,
 
     /// Name of the sticker set to which the sticker belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub set_name: Option<String>,
 
     /// For mask stickers, the position where the mask should be placed
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mask_position: Option<Box<MaskPosition>>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a sticker set.
@@ -975,11 +4771,25 @@ pub struct StickerSet {
     /// Sticker set title
     pub title: String,
 
+    /// True, if the sticker set contains animated stickers
+    /// See https://telegram.org/blog/animated-stickers
+    pub is_animated: bool,
+
     /// True, if the sticker set contains masks
     pub contains_masks: bool,
 
     /// List of all set stickers
     pub stickers: Vec<Box<Sticker>>,
+
+    /// Sticker set thumbnail in the .WEBP or .TGS format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<PhotoSize>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object describes the position on faces where a mask should be
@@ -1002,6 +4812,244 @@ pub struct MaskPosition {
 
     /// Mask scaling coefficient. For example, 2.0 means double size.
     pub scale: f64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Use this method to send static .WEBP or animated .TGS stickers. On
+/// success, the sent Message is returned.
+/// See https://telegram.org/blog/animated-stickers
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendSticker {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Sticker to send. Pass a file_id as String to send a file that exists on
+    /// the Telegram servers (recommended), pass an HTTP URL as a String for
+    /// Telegram to get a .WEBP file from the Internet, or upload a new one
+    /// using multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub sticker: Box<InputFile>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// Additional interface options. A JSON-serialized object for an inline
+    /// keyboard, custom reply keyboard, instructions to remove reply keyboard
+    /// or to force a reply from the user.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    /// See https://core.telegram.org/bots#keyboards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<ReplyMarkup>>,
+}
+
+impl Method for SendSticker {
+    const NAME: &'static str = "sendSticker";
+    type Response = Message;
+}
+
+/// Use this method to get a sticker set. On success, a StickerSet object is returned.
+/// See https://core.telegram.org/bots/api#stickerset
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetStickerSet {
+    /// Name of the sticker set
+    pub name: String,
+}
+
+impl Method for GetStickerSet {
+    const NAME: &'static str = "getStickerSet";
+    type Response = StickerSet;
+}
+
+/// Use this method to upload a .PNG file with a sticker for later use in
+/// createNewStickerSet and addStickerToSet methods (can be used multiple
+/// times). Returns the uploaded File on success.
+/// See https://core.telegram.org/bots/api#file
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadStickerFile {
+    /// User identifier of sticker file owner
+    pub user_id: UserId,
+
+    /// PNG image with the sticker, must be up to 512 kilobytes in size,
+    /// dimensions must not exceed 512px, and either width or height must be
+    /// exactly 512px. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    pub png_sticker: Box<InputFile>,
+}
+
+impl Method for UploadStickerFile {
+    const NAME: &'static str = "uploadStickerFile";
+    type Response = File;
+}
+
+/// Use this method to create a new sticker set owned by a user. The bot
+/// will be able to edit the sticker set thus created. You must use exactly
+/// one of the fields png_sticker or tgs_sticker. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateNewStickerSet {
+    /// User identifier of created sticker set owner
+    pub user_id: UserId,
+
+    /// Short name of sticker set, to be used in t.me/addstickers/ URLs (e.g.,
+    /// animals). Can contain only english letters, digits and underscores. Must
+    /// begin with a letter, can't contain consecutive underscores and must end
+    /// in “_by_<bot username>”. <bot_username> is case insensitive. 1-64 characters.
+    pub name: String,
+
+    /// Sticker set title, 1-64 characters
+    pub title: String,
+
+    /// PNG image with the sticker, must be up to 512 kilobytes in size,
+    /// dimensions must not exceed 512px, and either width or height must be
+    /// exactly 512px. Pass a file_id as a String to send a file that already
+    /// exists on the Telegram servers, pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, or upload a new one using
+    /// multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub png_sticker: Option<Box<InputFile>>,
+
+    /// TGS animation with the sticker, uploaded using multipart/form-data. See
+    /// https://core.telegram.org/animated_stickers#technical-requirements for
+    /// technical requirements
+    /// See https://core.telegram.org/animated_stickers#technical-requirements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tgs_sticker: Option<Box<InputFile>>,
+
+    /// One or more emoji corresponding to the sticker
+    pub emojis: String,
+
+    /// Pass True, if a set of mask stickers should be created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains_masks: Option<bool>,
+
+    /// A JSON-serialized object for position where the mask should be placed on faces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<Box<MaskPosition>>,
+}
+
+impl Method for CreateNewStickerSet {
+    const NAME: &'static str = "createNewStickerSet";
+    type Response = bool;
+}
+
+/// Use this method to add a new sticker to a set created by the bot. You
+/// must use exactly one of the fields png_sticker or tgs_sticker. Animated
+/// stickers can be added to animated sticker sets and only to them.
+/// Animated sticker sets can have up to 50 stickers. Static sticker sets
+/// can have up to 120 stickers. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddStickerToSet {
+    /// User identifier of sticker set owner
+    pub user_id: UserId,
+
+    /// Sticker set name
+    pub name: String,
+
+    /// PNG image with the sticker, must be up to 512 kilobytes in size,
+    /// dimensions must not exceed 512px, and either width or height must be
+    /// exactly 512px. Pass a file_id as a String to send a file that already
+    /// exists on the Telegram servers, pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, or upload a new one using
+    /// multipart/form-data. More info on Sending Files »
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub png_sticker: Option<Box<InputFile>>,
+
+    /// TGS animation with the sticker, uploaded using multipart/form-data. See
+    /// https://core.telegram.org/animated_stickers#technical-requirements for
+    /// technical requirements
+    /// See https://core.telegram.org/animated_stickers#technical-requirements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tgs_sticker: Option<Box<InputFile>>,
+
+    /// One or more emoji corresponding to the sticker
+    pub emojis: String,
+
+    /// A JSON-serialized object for position where the mask should be placed on faces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<Box<MaskPosition>>,
+}
+
+impl Method for AddStickerToSet {
+    const NAME: &'static str = "addStickerToSet";
+    type Response = bool;
+}
+
+/// Use this method to move a sticker in a set created by the bot to a
+/// specific position. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerPositionInSet {
+    /// File identifier of the sticker
+    pub sticker: String,
+
+    /// New sticker position in the set, zero-based
+    pub position: i64,
+}
+
+impl Method for SetStickerPositionInSet {
+    const NAME: &'static str = "setStickerPositionInSet";
+    type Response = bool;
+}
+
+/// Use this method to delete a sticker from a set created by the bot.
+/// Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteStickerFromSet {
+    /// File identifier of the sticker
+    pub sticker: String,
+}
+
+impl Method for DeleteStickerFromSet {
+    const NAME: &'static str = "deleteStickerFromSet";
+    type Response = bool;
+}
+
+/// Use this method to set the thumbnail of a sticker set. Animated
+/// thumbnails can be set for animated sticker sets only. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerSetThumb {
+    /// Sticker set name
+    pub name: String,
+
+    /// User identifier of the sticker set owner
+    pub user_id: UserId,
+
+    /// A PNG image with the thumbnail, must be up to 128 kilobytes in size and
+    /// have width and height exactly 100px, or a TGS animation with the
+    /// thumbnail up to 32 kilobytes in size; see
+    /// https://core.telegram.org/animated_stickers#technical-requirements for
+    /// animated sticker technical requirements. Pass a file_id as a String to
+    /// send a file that already exists on the Telegram servers, pass an HTTP
+    /// URL as a String for Telegram to get a file from the Internet, or upload
+    /// a new one using multipart/form-data. More info on Sending Files ».
+    /// Animated sticker set thumbnail can't be uploaded via HTTP URL.
+    /// See https://core.telegram.org/animated_stickers#technical-requirements
+    /// See https://core.telegram.org/bots/api#sending-files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<Box<InputFile>>,
+}
+
+impl Method for SetStickerSetThumb {
+    const NAME: &'static str = "setStickerSetThumb";
+    type Response = bool;
 }
 
 /// This object represents an incoming inline query. When the user sends an
@@ -1009,28 +5057,94 @@ pub struct MaskPosition {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQuery {
     /// Unique identifier for this query
-    pub id: String,
+    pub id: InlineQueryId,
 
     /// Sender
     pub from: Box<User>,
 
-    /// Sender location, only for bots that request user location
-    pub location: Option<Box<Location>>,
-
-    /// Text of the query (up to 512 characters)
+    /// Text of the query (up to 256 characters)
     pub query: String,
 
     /// Offset of the results to be returned, can be controlled by the bot
     pub offset: String,
+
+    /// Type of the chat, from which the inline query was sent. Can be either
+    /// “sender” for a private chat with the inline query sender, “private”,
+    /// “group”, “supergroup”, or “channel”. The chat type should be always
+    /// known for requests sent from official clients and most third-party
+    /// clients, unless the request was sent from a secret chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_type: Option<String>,
+
+    /// Sender location, only for bots that request user location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Box<Location>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Use this method to send answers to an inline query. On success, True is
+/// returned.No more than 50 results per query are allowed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerInlineQuery {
+    /// Unique identifier for the answered query
+    pub inline_query_id: InlineQueryId,
+
+    /// A JSON-serialized array of results for the inline query
+    pub results: Vec<Box<InlineQueryResult>>,
+
+    /// The maximum amount of time in seconds that the result of the inline
+    /// query may be cached on the server. Defaults to 300.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i64>,
+
+    /// Pass True, if results may be cached on the server side only for the user
+    /// that sent the query. By default, results may be returned to any user who
+    /// sends the same query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_personal: Option<bool>,
+
+    /// Pass the offset that a client should send in the next query with the
+    /// same text to receive more results. Pass an empty string if there are no
+    /// more results or if you don't support pagination. Offset length can't
+    /// exceed 64 bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<String>,
+
+    /// If passed, clients will display a button with specified text that
+    /// switches the user to a private chat with the bot and sends the bot a
+    /// start message with the parameter switch_pm_parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_pm_text: Option<String>,
+
+    /// Deep-linking parameter for the /start message sent to the bot when user
+    /// presses the switch button. 1-64 characters, only A-Z, a-z, 0-9, _ and -
+    /// are allowed.Example: An inline bot that sends YouTube videos can ask the
+    /// user to connect the bot to their YouTube account to adapt search results
+    /// accordingly. To do this, it displays a 'Connect your YouTube account'
+    /// button above the results, or even before showing any. The user presses
+    /// the button, switches to a private chat with the bot and, in doing so,
+    /// passes a start parameter that instructs the bot to return an oauth link.
+    /// Once done, the bot can offer a switch_inline button so that the user can
+    /// easily return to the chat where they wanted to use the bot's inline capabilities.
+    /// See https://core.telegram.org/bots/api/bots#deep-linking
+    /// See https://core.telegram.org/bots/api#inlinekeyboardmarkup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_pm_parameter: Option<String>,
+}
+
+impl Method for AnswerInlineQuery {
+    const NAME: &'static str = "answerInlineQuery";
+    type Response = bool;
 }
 
 /// Represents a link to an article or web page.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultArticle {
-    /// Type of the result, must be article
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
 
@@ -1042,25 +5156,38 @@ pub struct InlineQueryResultArticle {
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// URL of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 
     /// Pass True, if you don't want the URL to be shown in the message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_url: Option<bool>,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
 
     /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_width: Option<i64>,
 
     /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_height: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a photo. By default, this photo will be sent by the
@@ -1069,10 +5196,6 @@ pub struct InlineQueryResultArticle {
 /// instead of the photo.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultPhoto {
-    /// Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1084,33 +5207,50 @@ pub struct InlineQueryResultPhoto {
     pub thumb_url: String,
 
     /// Width of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_width: Option<i64>,
 
     /// Height of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_height: Option<i64>,
 
     /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Caption of the photo to be sent, 0-200 characters
+    /// Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the photo caption. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to an animated GIF file. By default, this animated GIF
@@ -1119,10 +5259,6 @@ pub struct InlineQueryResultPhoto {
 /// content instead of the animation.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultGif {
-    /// Type of the result, must be gif
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1130,36 +5266,58 @@ pub struct InlineQueryResultGif {
     pub gif_url: String,
 
     /// Width of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gif_width: Option<i64>,
 
     /// Height of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gif_height: Option<i64>,
 
     /// Duration of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gif_duration: Option<i64>,
 
-    /// URL of the static thumbnail for the result (jpeg or gif)
+    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
     pub thumb_url: String,
 
+    /// MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or
+    /// “video/mp4”. Defaults to “image/jpeg”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_mime_type: Option<String>,
+
     /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
-    /// Caption of the GIF file to be sent, 0-200 characters
+    /// Caption of the GIF file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the caption. See formatting options for
+    /// more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the GIF animation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a video animation (H.264/MPEG-4 AVC video without
@@ -1168,10 +5326,6 @@ pub struct InlineQueryResultGif {
 /// to send a message with the specified content instead of the animation.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultMpeg4Gif {
-    /// Type of the result, must be mpeg4_gif
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1179,36 +5333,58 @@ pub struct InlineQueryResultMpeg4Gif {
     pub mpeg4_url: String,
 
     /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpeg4_width: Option<i64>,
 
     /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpeg4_height: Option<i64>,
 
     /// Video duration
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpeg4_duration: Option<i64>,
 
-    /// URL of the static thumbnail (jpeg or gif) for the result
+    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
     pub thumb_url: String,
 
+    /// MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or
+    /// “video/mp4”. Defaults to “image/jpeg”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_mime_type: Option<String>,
+
     /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
-    /// Caption of the MPEG-4 file to be sent, 0-200 characters
+    /// Caption of the MPEG-4 file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the caption. See formatting options for
+    /// more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a page containing an embedded video player or a
@@ -1217,10 +5393,6 @@ pub struct InlineQueryResultMpeg4Gif {
 /// send a message with the specified content instead of the video.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultVideo {
-    /// Type of the result, must be video
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1236,47 +5408,60 @@ pub struct InlineQueryResultVideo {
     /// Title for the result
     pub title: String,
 
-    /// Caption of the video to be sent, 0-200 characters
+    /// Caption of the video to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the video caption. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_width: Option<i64>,
 
     /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_height: Option<i64>,
 
     /// Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_duration: Option<i64>,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the video. This field is
     /// required if InlineQueryResultVideo is used to send an HTML-page as a
     /// result (e.g., a YouTube video).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// Represents a link to an mp3 audio file. By default, this audio file will
+/// Represents a link to an MP3 audio file. By default, this audio file will
 /// be sent by the user. Alternatively, you can use input_message_content to
 /// send a message with the specified content instead of the audio.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultAudio {
-    /// Type of the result, must be audio
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1286,40 +5471,51 @@ pub struct InlineQueryResultAudio {
     /// Title
     pub title: String,
 
-    /// Caption, 0-200 characters
+    /// Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the audio caption. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Performer
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub performer: Option<String>,
 
     /// Audio duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub audio_duration: Option<i64>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// Represents a link to a voice recording in an .ogg container encoded with
+/// Represents a link to a voice recording in an .OGG container encoded with
 /// OPUS. By default, this voice recording will be sent by the user.
 /// Alternatively, you can use input_message_content to send a message with
 /// the specified content instead of the the voice message.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultVoice {
-    /// Type of the result, must be voice
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1329,25 +5525,39 @@ pub struct InlineQueryResultVoice {
     /// Recording title
     pub title: String,
 
-    /// Caption, 0-200 characters
+    /// Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the voice message caption. See formatting
+    /// options for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Recording duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub voice_duration: Option<i64>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the voice recording
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a file. By default, this file will be sent by the
@@ -1357,25 +5567,26 @@ pub struct InlineQueryResultVoice {
 /// using this method.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultDocument {
-    /// Type of the result, must be document
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// Title for the result
     pub title: String,
 
-    /// Caption of the document to be sent, 0-200 characters
+    /// Caption of the document to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// A valid URL for the file
     pub document_url: String,
@@ -1384,22 +5595,34 @@ pub struct InlineQueryResultDocument {
     pub mime_type: String,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
 
     /// URL of the thumbnail (jpeg only) for the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
 
     /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_width: Option<i64>,
 
     /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_height: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a location on a map. By default, the location will be sent by
@@ -1407,10 +5630,6 @@ pub struct InlineQueryResultDocument {
 /// message with the specified content instead of the location.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultLocation {
-    /// Type of the result, must be location
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
 
@@ -1423,25 +5642,52 @@ pub struct InlineQueryResultLocation {
     /// Location title
     pub title: String,
 
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
     /// Period in seconds for which the location can be updated, should be
     /// between 60 and 86400.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub live_period: Option<i64>,
 
+    /// For live locations, a direction in which the user is moving, in degrees.
+    /// Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+
+    /// For live locations, a maximum distance for proximity alerts about
+    /// approaching another chat member, in meters. Must be between 1 and 100000
+    /// if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the location
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
 
     /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
 
     /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_width: Option<i64>,
 
     /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_height: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a venue. By default, the venue will be sent by the user.
@@ -1449,10 +5695,6 @@ pub struct InlineQueryResultLocation {
 /// the specified content instead of the venue.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultVenue {
-    /// Type of the result, must be venue
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
 
@@ -1469,34 +5711,56 @@ pub struct InlineQueryResultVenue {
     pub address: String,
 
     /// Foursquare identifier of the venue if known
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_id: Option<String>,
 
+    /// Foursquare type of the venue, if known. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+
+    /// Google Places type of the venue. (See supported types.)
+    /// See https://developers.google.com/places/web-service/supported_types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
 
     /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
 
     /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_width: Option<i64>,
 
     /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_height: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a contact with a phone number. By default, this contact will
 /// be sent by the user. Alternatively, you can use input_message_content to
 /// send a message with the specified content instead of the contact.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct InlineQueryResultContact {
-    /// Type of the result, must be contact
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
+pub struct InlineQueryResultContact {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
 
@@ -1507,33 +5771,46 @@ pub struct InlineQueryResultContact {
     pub first_name: String,
 
     /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    /// See https://en.wikipedia.org/wiki/VCard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
 
     /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
 
     /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_width: Option<i64>,
 
     /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_height: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a Game.
 /// See https://core.telegram.org/bots/api#games
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultGame {
-    /// Type of the result, must be game
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1542,7 +5819,14 @@ pub struct InlineQueryResultGame {
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a photo stored on the Telegram servers. By default,
@@ -1551,38 +5835,49 @@ pub struct InlineQueryResultGame {
 /// the specified content instead of the photo.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedPhoto {
-    /// Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier of the photo
-    pub photo_file_id: String,
+    pub photo_file_id: FileId,
 
     /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Caption of the photo to be sent, 0-200 characters
+    /// Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the photo caption. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to an animated GIF file stored on the Telegram
@@ -1592,35 +5887,45 @@ pub struct InlineQueryResultCachedPhoto {
 /// of the animation.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedGif {
-    /// Type of the result, must be gif
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier for the GIF file
-    pub gif_file_id: String,
+    pub gif_file_id: FileId,
 
     /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
-    /// Caption of the GIF file to be sent, 0-200 characters
+    /// Caption of the GIF file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the caption. See formatting options for
+    /// more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the GIF animation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a video animation (H.264/MPEG-4 AVC video without
@@ -1630,35 +5935,45 @@ pub struct InlineQueryResultCachedGif {
 /// content instead of the animation.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedMpeg4Gif {
-    /// Type of the result, must be mpeg4_gif
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier for the MP4 file
-    pub mpeg4_file_id: String,
+    pub mpeg4_file_id: FileId,
 
     /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
-    /// Caption of the MPEG-4 file to be sent, 0-200 characters
+    /// Caption of the MPEG-4 file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the caption. See formatting options for
+    /// more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a sticker stored on the Telegram servers. By
@@ -1667,22 +5982,26 @@ pub struct InlineQueryResultCachedMpeg4Gif {
 /// instead of the sticker.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedSticker {
-    /// Type of the result, must be sticker
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier of the sticker
-    pub sticker_file_id: String,
+    pub sticker_file_id: FileId,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a file stored on the Telegram servers. By default,
@@ -1691,10 +6010,6 @@ pub struct InlineQueryResultCachedSticker {
 /// the specified content instead of the file.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedDocument {
-    /// Type of the result, must be document
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
@@ -1702,27 +6017,41 @@ pub struct InlineQueryResultCachedDocument {
     pub title: String,
 
     /// A valid file identifier for the file
-    pub document_file_id: String,
+    pub document_file_id: FileId,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Caption of the document to be sent, 0-200 characters
+    /// Caption of the document to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the document caption. See formatting
+    /// options for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a video file stored on the Telegram servers. By
@@ -1731,38 +6060,48 @@ pub struct InlineQueryResultCachedDocument {
 /// message with the specified content instead of the video.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedVideo {
-    /// Type of the result, must be video
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier for the video file
-    pub video_file_id: String,
+    pub video_file_id: FileId,
 
     /// Title for the result
     pub title: String,
 
     /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Caption of the video to be sent, 0-200 characters
+    /// Caption of the video to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the video caption. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a link to a voice message stored on the Telegram servers. By
@@ -1771,69 +6110,87 @@ pub struct InlineQueryResultCachedVideo {
 /// content instead of the voice message.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedVoice {
-    /// Type of the result, must be voice
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier for the voice message
-    pub voice_file_id: String,
+    pub voice_file_id: FileId,
 
     /// Voice message title
     pub title: String,
 
-    /// Caption, 0-200 characters
+    /// Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the voice message caption. See formatting
+    /// options for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the voice message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// Represents a link to an mp3 audio file stored on the Telegram servers.
+/// Represents a link to an MP3 audio file stored on the Telegram servers.
 /// By default, this audio file will be sent by the user. Alternatively, you
 /// can use input_message_content to send a message with the specified
 /// content instead of the audio.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineQueryResultCachedAudio {
-    /// Type of the result, must be audio
-    #[serde(rename = "type")]
-    pub type_tl: String,
-
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
 
     /// A valid file identifier for the audio file
-    pub audio_file_id: String,
+    pub audio_file_id: FileId,
 
-    /// Caption, 0-200 characters
+    /// Caption, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in the media caption.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the audio caption. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Inline keyboard attached to the message
     /// See https://core.telegram.org/bots/api/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
 
     /// Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<Box<InputMessageContent>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents the content of a text message to be sent as the result of an
@@ -1844,15 +6201,26 @@ pub struct InputTextMessageContent {
     /// Text of the message to be sent, 1-4096 characters
     pub message_text: String,
 
-    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
-    /// fixed-width text or inline URLs in your bot's message.
-    /// See https://core.telegram.org/bots/api#markdown-style
-    /// See https://core.telegram.org/bots/api#html-style
+    /// Mode for parsing entities in the message text. See formatting options
+    /// for more details.
     /// See https://core.telegram.org/bots/api#formatting-options
-    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in message text, which can be
+    /// specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Disables link previews for links in the sent message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_web_page_preview: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents the content of a location message to be sent as the result of
@@ -1866,9 +6234,31 @@ pub struct InputLocationMessageContent {
     /// Longitude of the location in degrees
     pub longitude: f64,
 
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
     /// Period in seconds for which the location can be updated, should be
     /// between 60 and 86400.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub live_period: Option<i64>,
+
+    /// For live locations, a direction in which the user is moving, in degrees.
+    /// Must be between 1 and 360 if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i64>,
+
+    /// For live locations, a maximum distance for proximity alerts about
+    /// approaching another chat member, in meters. Must be between 1 and 100000
+    /// if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i64>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents the content of a venue message to be sent as the result of an
@@ -1889,7 +6279,28 @@ pub struct InputVenueMessageContent {
     pub address: String,
 
     /// Foursquare identifier of the venue, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_id: Option<String>,
+
+    /// Foursquare type of the venue, if known. (For example,
+    /// “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+
+    /// Google Places type of the venue. (See supported types.)
+    /// See https://developers.google.com/places/web-service/supported_types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents the content of a contact message to be sent as the result of
@@ -1904,7 +6315,121 @@ pub struct InputContactMessageContent {
     pub first_name: String,
 
     /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
+
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    /// See https://en.wikipedia.org/wiki/VCard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents the content of an invoice message to be sent as the result of
+/// an inline query.
+/// See https://core.telegram.org/bots/api#inputmessagecontent
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputInvoiceMessageContent {
+    /// Product name, 1-32 characters
+    pub title: String,
+
+    /// Product description, 1-255 characters
+    pub description: String,
+
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to
+    /// the user, use for your internal processes.
+    pub payload: String,
+
+    /// Payment provider token, obtained via Botfather
+    /// See https://t.me/botfather
+    pub provider_token: String,
+
+    /// Three-letter ISO 4217 currency code, see more on currencies
+    /// See https://core.telegram.org/bots/api/bots/payments#supported-currencies
+    pub currency: String,
+
+    /// Price breakdown, a JSON-serialized list of components (e.g. product
+    /// price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    pub prices: Vec<Box<LabeledPrice>>,
+
+    /// The maximum accepted amount for tips in the smallest units of the
+    /// currency (integer, not float/double). For example, for a maximum tip of
+    /// US$ 1.45 pass max_tip_amount = 145. See the exp parameter in
+    /// currencies.json, it shows the number of digits past the decimal point
+    /// for each currency (2 for the majority of currencies). Defaults to 0
+    /// See https://core.telegram.org/bots/payments/currencies.json
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+
+    /// A JSON-serialized array of suggested amounts of tip in the smallest
+    /// units of the currency (integer, not float/double). At most 4 suggested
+    /// tip amounts can be specified. The suggested tip amounts must be
+    /// positive, passed in a strictly increased order and must not exceed max_tip_amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i64>>,
+
+    /// A JSON-serialized object for data about the invoice, which will be
+    /// shared with the payment provider. A detailed description of the required
+    /// fields should be provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+
+    /// URL of the product photo for the invoice. Can be a photo of the goods or
+    /// a marketing image for a service. People like it better when they see
+    /// what they are paying for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+
+    /// Photo size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+
+    /// Pass True, if you require the user's full name to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+
+    /// Pass True, if you require the user's phone number to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+
+    /// Pass True, if you require the user's email address to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+
+    /// Pass True, if you require the user's shipping address to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+
+    /// Pass True, if user's phone number should be sent to provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+
+    /// Pass True, if user's email address should be sent to provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+
+    /// Pass True, if the final price depends on the shipping method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Represents a result of an inline query that was chosen by the user and
@@ -1919,6 +6444,7 @@ pub struct ChosenInlineResult {
     pub from: Box<User>,
 
     /// Sender location, only for bots that require user location
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<Box<Location>>,
 
     /// Identifier of the sent inline message. Available only if there is an
@@ -1927,10 +6453,212 @@ pub struct ChosenInlineResult {
     /// See https://core.telegram.org/bots/api#inlinekeyboardmarkup
     /// See https://core.telegram.org/bots/api#callbackquery
     /// See https://core.telegram.org/bots/api#updating-messages
-    pub inline_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
 
     /// The query that was used to obtain the result
     pub query: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Use this method to send invoices. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendInvoice {
+    /// Unique identifier for the target chat or username of the target channel
+    /// (in the format @channelusername)
+    pub chat_id: Recipient,
+
+    /// Product name, 1-32 characters
+    pub title: String,
+
+    /// Product description, 1-255 characters
+    pub description: String,
+
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to
+    /// the user, use for your internal processes.
+    pub payload: String,
+
+    /// Payments provider token, obtained via Botfather
+    /// See https://t.me/botfather
+    pub provider_token: String,
+
+    /// Three-letter ISO 4217 currency code, see more on currencies
+    /// See https://core.telegram.org/bots/api/bots/payments#supported-currencies
+    pub currency: String,
+
+    /// Price breakdown, a JSON-serialized list of components (e.g. product
+    /// price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    pub prices: Vec<Box<LabeledPrice>>,
+
+    /// The maximum accepted amount for tips in the smallest units of the
+    /// currency (integer, not float/double). For example, for a maximum tip of
+    /// US$ 1.45 pass max_tip_amount = 145. See the exp parameter in
+    /// currencies.json, it shows the number of digits past the decimal point
+    /// for each currency (2 for the majority of currencies). Defaults to 0
+    /// See https://core.telegram.org/bots/payments/currencies.json
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+
+    /// A JSON-serialized array of suggested amounts of tips in the smallest
+    /// units of the currency (integer, not float/double). At most 4 suggested
+    /// tip amounts can be specified. The suggested tip amounts must be
+    /// positive, passed in a strictly increased order and must not exceed max_tip_amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i64>>,
+
+    /// Unique deep-linking parameter. If left empty, forwarded copies of the
+    /// sent message will have a Pay button, allowing multiple users to pay
+    /// directly from the forwarded message, using the same invoice. If
+    /// non-empty, forwarded copies of the sent message will have a URL button
+    /// with a deep link to the bot (instead of a Pay button), with the value
+    /// used as the start parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+
+    /// A JSON-serialized data about the invoice, which will be shared with the
+    /// payment provider. A detailed description of required fields should be
+    /// provided by the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+
+    /// URL of the product photo for the invoice. Can be a photo of the goods or
+    /// a marketing image for a service. People like it better when they see
+    /// what they are paying for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+
+    /// Photo size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i64>,
+
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i64>,
+
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i64>,
+
+    /// Pass True, if you require the user's full name to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+
+    /// Pass True, if you require the user's phone number to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+
+    /// Pass True, if you require the user's email address to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+
+    /// Pass True, if you require the user's shipping address to complete the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+
+    /// Pass True, if user's phone number should be sent to provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+
+    /// Pass True, if user's email address should be sent to provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+
+    /// Pass True, if the final price depends on the shipping method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// A JSON-serialized object for an inline keyboard. If empty, one 'Pay
+    /// total price' button will be shown. If not empty, the first button must
+    /// be a Pay button.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
+
+impl Method for SendInvoice {
+    const NAME: &'static str = "sendInvoice";
+    type Response = Message;
+}
+
+/// If you sent an invoice requesting a shipping address and the parameter
+/// is_flexible was specified, the Bot API will send an Update with a
+/// shipping_query field to the bot. Use this method to reply to shipping
+/// queries. On success, True is returned.
+/// See https://core.telegram.org/bots/api#update
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerShippingQuery {
+    /// Unique identifier for the query to be answered
+    pub shipping_query_id: ShippingQueryId,
+
+    /// Specify True if delivery to the specified address is possible and False
+    /// if there are any problems (for example, if delivery to the specified
+    /// address is not possible)
+    pub ok: bool,
+
+    /// Required if ok is True. A JSON-serialized array of available shipping options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<Box<ShippingOption>>>,
+
+    /// Required if ok is False. Error message in human readable form that
+    /// explains why it is impossible to complete the order (e.g. "Sorry,
+    /// delivery to your desired address is unavailable'). Telegram will display
+    /// this message to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl Method for AnswerShippingQuery {
+    const NAME: &'static str = "answerShippingQuery";
+    type Response = bool;
+}
+
+/// Once the user has confirmed their payment and shipping details, the Bot
+/// API sends the final confirmation in the form of an Update with the field
+/// pre_checkout_query. Use this method to respond to such pre-checkout
+/// queries. On success, True is returned. Note: The Bot API must receive an
+/// answer within 10 seconds after the pre-checkout query was sent.
+/// See https://core.telegram.org/bots/api#update
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerPreCheckoutQuery {
+    /// Unique identifier for the query to be answered
+    pub pre_checkout_query_id: PreCheckoutQueryId,
+
+    /// Specify True if everything is alright (goods are available, etc.) and
+    /// the bot is ready to proceed with the order. Use False if there are any problems.
+    pub ok: bool,
+
+    /// Required if ok is False. Error message in human readable form that
+    /// explains the reason for failure to proceed with the checkout (e.g.
+    /// "Sorry, somebody just bought the last of our amazing black T-shirts
+    /// while you were busy filling out your payment details. Please choose a
+    /// different color or garment!"). Telegram will display this message to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl Method for AnswerPreCheckoutQuery {
+    const NAME: &'static str = "answerPreCheckoutQuery";
+    type Response = bool;
 }
 
 /// This object represents a portion of the price for goods or services.
@@ -1946,6 +6674,12 @@ pub struct LabeledPrice {
     /// See https://core.telegram.org/bots/api/bots/payments#supported-currencies
     /// See https://core.telegram.org/bots/payments/currencies.json
     pub amount: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object contains basic information about an invoice.
@@ -1970,6 +6704,12 @@ pub struct Invoice {
     /// past the decimal point for each currency (2 for the majority of currencies).
     /// See https://core.telegram.org/bots/payments/currencies.json
     pub total_amount: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents a shipping address.
@@ -1992,22 +6732,38 @@ pub struct ShippingAddress {
 
     /// Address post code
     pub post_code: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents information about an order.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OrderInfo {
     /// User name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// User's phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_number: Option<String>,
 
     /// User email
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
 
     /// User shipping address
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_address: Option<Box<ShippingAddress>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object represents one shipping option.
@@ -2021,6 +6777,12 @@ pub struct ShippingOption {
 
     /// List of price portions
     pub prices: Vec<Box<LabeledPrice>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object contains basic information about a successful payment.
@@ -2041,9 +6803,11 @@ pub struct SuccessfulPayment {
     pub invoice_payload: String,
 
     /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_option_id: Option<String>,
 
     /// Order info provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub order_info: Option<Box<OrderInfo>>,
 
     /// Telegram payment identifier
@@ -2051,13 +6815,19 @@ pub struct SuccessfulPayment {
 
     /// Provider payment identifier
     pub provider_payment_charge_id: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object contains information about an incoming shipping query.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ShippingQuery {
     /// Unique query identifier
-    pub id: String,
+    pub id: ShippingQueryId,
 
     /// User who sent the query
     pub from: Box<User>,
@@ -2067,13 +6837,19 @@ pub struct ShippingQuery {
 
     /// User specified shipping address
     pub shipping_address: Box<ShippingAddress>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// This object contains information about an incoming pre-checkout query.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PreCheckoutQuery {
     /// Unique query identifier
-    pub id: String,
+    pub id: PreCheckoutQueryId,
 
     /// User who sent the query
     pub from: Box<User>,
@@ -2093,10 +6869,308 @@ pub struct PreCheckoutQuery {
     pub invoice_payload: String,
 
     /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_option_id: Option<String>,
 
     /// Order info provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub order_info: Option<Box<OrderInfo>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// This object represents a file uploaded to Telegram Passport. Currently
+/// all Telegram Passport files are in JPEG format when decrypted and don't
+/// exceed 10MB.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportFile {
+    /// Identifier for this file, which can be used to download or reuse the file
+    pub file_id: FileId,
+
+    /// Unique identifier for this file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the file.
+    pub file_unique_id: FileUniqueId,
+
+    /// File size
+    pub file_size: i64,
+
+    /// Unix time when the file was uploaded
+    pub file_date: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Informs a user that some of the Telegram Passport elements they provided
+/// contains errors. The user will not be able to re-submit their Passport
+/// to you until the errors are fixed (the contents of the field for which
+/// you returned the error must change). Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetPassportDataErrors {
+    /// User identifier
+    pub user_id: UserId,
+
+    /// A JSON-serialized array describing the errors
+    pub errors: Vec<Box<PassportElementError>>,
+}
+
+impl Method for SetPassportDataErrors {
+    const NAME: &'static str = "setPassportDataErrors";
+    type Response = bool;
+}
+
+/// Represents an issue in one of the data fields that was provided by the
+/// user. The error is considered resolved when the field's value changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorDataField {
+    /// The section of the user's Telegram Passport which has the error, one of
+    /// “personal_details”, “passport”, “driver_license”, “identity_card”,
+    /// “internal_passport”, “address”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Name of the data field which has the error
+    pub field_name: String,
+
+    /// Base64-encoded data hash
+    pub data_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with the front side of a document. The error is
+/// considered resolved when the file with the front side of the document changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorFrontSide {
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “passport”, “driver_license”, “identity_card”, “internal_passport”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded hash of the file with the front side of the document
+    pub file_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with the reverse side of a document. The error is
+/// considered resolved when the file with reverse side of the document changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorReverseSide {
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “driver_license”, “identity_card”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded hash of the file with the reverse side of the document
+    pub file_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with the selfie with a document. The error is
+/// considered resolved when the file with the selfie changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorSelfie {
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “passport”, “driver_license”, “identity_card”, “internal_passport”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded hash of the file with the selfie
+    pub file_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with a document scan. The error is considered
+/// resolved when the file with the document scan changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorFile {
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded file hash
+    pub file_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with a list of scans. The error is considered
+/// resolved when the list of files containing the scans changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorFiles {
+    /// The section of the user's Telegram Passport which has the issue, one of
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// List of base64-encoded file hashes
+    pub file_hashes: Vec<String>,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with one of the files that constitute the
+/// translation of a document. The error is considered resolved when the
+/// file changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorTranslationFile {
+    /// Type of element of the user's Telegram Passport which has the issue, one
+    /// of “passport”, “driver_license”, “identity_card”, “internal_passport”,
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded file hash
+    pub file_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue with the translated version of a document. The error
+/// is considered resolved when a file with the document translation change.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorTranslationFiles {
+    /// Type of element of the user's Telegram Passport which has the issue, one
+    /// of “passport”, “driver_license”, “identity_card”, “internal_passport”,
+    /// “utility_bill”, “bank_statement”, “rental_agreement”,
+    /// “passport_registration”, “temporary_registration”
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// List of base64-encoded file hashes
+    pub file_hashes: Vec<String>,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Represents an issue in an unspecified place. The error is considered
+/// resolved when new data is added.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportElementErrorUnspecified {
+    /// Type of element of the user's Telegram Passport which has the issue
+    #[serde(rename = "type")]
+    pub type_tl: String,
+
+    /// Base64-encoded element hash
+    pub element_hash: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Use this method to send a game. On success, the sent Message is returned.
+/// See https://core.telegram.org/bots/api#message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendGame {
+    /// Unique identifier for the target chat
+    pub chat_id: ChatId,
+
+    /// Short name of the game, serves as the unique identifier for the game.
+    /// Set up your games via Botfather.
+    /// See https://t.me/botfather
+    pub game_short_name: String,
+
+    /// Sends the message silently. Users will receive a notification with no sound.
+    /// See https://telegram.org/blog/channels-2-0#silent-messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<MessageIdentifier>,
+
+    /// Pass True, if the message should be sent even if the specified
+    /// replied-to message is not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+
+    /// A JSON-serialized object for an inline keyboard. If empty, one 'Play
+    /// game_title' button will be shown. If not empty, the first button must
+    /// launch the game.
+    /// See https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<Box<InlineKeyboardMarkup>>,
+}
+
+impl Method for SendGame {
+    const NAME: &'static str = "sendGame";
+    type Response = Message;
 }
 
 /// This object represents a game. Use BotFather to create and edit games,
@@ -2118,40 +7192,98 @@ pub struct Game {
     /// editMessageText. 0-4096 characters.
     /// See https://core.telegram.org/bots/api#setgamescore
     /// See https://core.telegram.org/bots/api#editmessagetext
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
     /// Special entities that appear in text, such as usernames, URLs, bot
     /// commands, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text_entities: Option<Vec<Box<MessageEntity>>>,
 
     /// Animation that will be displayed in the game message in chats. Upload
     /// via BotFather
     /// See https://t.me/botfather
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub animation: Option<Box<Animation>>,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// You can provide an animation for your game so that it looks stylish in
-/// chats (check out Lumberjack for an example). This object represents an
-/// animation file to be displayed in the message containing a game.
-/// See https://core.telegram.org/bots/api#game
-/// See https://t.me/gamebot
-/// See https://core.telegram.org/bots/api#games
+/// Use this method to set the score of the specified user in a game
+/// message. On success, if the message is not an inline message, the
+/// Message is returned, otherwise True is returned. Returns an error, if
+/// the new score is not greater than the user's current score in the chat
+/// and force is False.
+/// See https://core.telegram.org/bots/api#message
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Animation {
-    /// Unique file identifier
-    pub file_id: String,
+pub struct SetGameScore {
+    /// User identifier
+    pub user_id: UserId,
 
-    /// Animation thumbnail as defined by sender
-    pub thumb: Option<Box<PhotoSize>>,
+    /// New score, must be non-negative
+    pub score: i64,
 
-    /// Original animation filename as defined by sender
-    pub file_name: Option<String>,
+    /// Pass True, if the high score is allowed to decrease. This can be useful
+    /// when fixing mistakes or banning cheaters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+
+    /// Pass True, if the game message should not be automatically edited to
+    /// include the current scoreboard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_edit_message: Option<bool>,
+
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatId>,
+
+    /// Required if inline_message_id is not specified. Identifier of the sent message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+}
 
-    /// MIME type of the file as defined by sender
-    pub mime_type: Option<String>,
+impl Method for SetGameScore {
+    const NAME: &'static str = "setGameScore";
+    type Response = MessageOrBool;
+}
 
-    /// File size
-    pub file_size: Option<i64>,
+/// Use this method to get data for high score tables. Will return the score
+/// of the specified user and several of their neighbors in a game. On
+/// success, returns an Array of GameHighScore objects.
+/// See https://core.telegram.org/bots/api#gamehighscore
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetGameHighScores {
+    /// Target user id
+    pub user_id: UserId,
+
+    /// Required if inline_message_id is not specified. Unique identifier for
+    /// the target chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatId>,
+
+    /// Required if inline_message_id is not specified. Identifier of the sent message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageIdentifier>,
+
+    /// Required if chat_id and message_id are not specified. Identifier of the
+    /// inline message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<InlineMessageId>,
+}
+
+impl Method for GetGameHighScores {
+    const NAME: &'static str = "getGameHighScores";
+    type Response = Vec<GameHighScore>;
 }
 
 /// This object represents one row of the high scores table for a game.
@@ -2165,4 +7297,10 @@ pub struct GameHighScore {
 
     /// Score
     pub score: i64,
+
+    /// Fields unknown to this version of the crate, written back on
+    /// serialization
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
@@ -0,0 +1,316 @@
+use {
+    Animation, Audio, ChatId, Contact, Dice, Document, Game, Invoice, Location, Message,
+    MessageAutoDeleteTimerChanged, MessageEntity, PassportData, PhotoSize, Poll,
+    ProximityAlertTriggered, Sticker, SuccessfulPayment, User, Venue, Video, VideoNote, Voice,
+    VoiceChatEnded, VoiceChatParticipantsInvited, VoiceChatScheduled, VoiceChatStarted,
+};
+
+/// What a message is about: its content or the service event it reports.
+/// Borrowed from a Message with `Message::kind`.
+#[derive(Debug, Clone, Copy)]
+pub enum MessageKind<'a> {
+    /// Text message with special entities like usernames, URLs, bot commands
+    Text {
+        text: &'a str,
+        entities: &'a [Box<MessageEntity>],
+    },
+    /// Animation, also sent as a document for backward compatibility
+    Animation {
+        animation: &'a Animation,
+        caption: Option<&'a str>,
+        caption_entities: &'a [Box<MessageEntity>],
+    },
+    /// Audio file to be treated as music
+    Audio {
+        audio: &'a Audio,
+        caption: Option<&'a str>,
+        caption_entities: &'a [Box<MessageEntity>],
+    },
+    /// General file
+    Document {
+        document: &'a Document,
+        caption: Option<&'a str>,
+        caption_entities: &'a [Box<MessageEntity>],
+    },
+    /// Game
+    Game(&'a Game),
+    /// Available sizes of the photo
+    Photo {
+        photo: &'a [Box<PhotoSize>],
+        caption: Option<&'a str>,
+        caption_entities: &'a [Box<MessageEntity>],
+    },
+    /// Sticker
+    Sticker(&'a Sticker),
+    /// Video
+    Video {
+        video: &'a Video,
+        caption: Option<&'a str>,
+        caption_entities: &'a [Box<MessageEntity>],
+    },
+    /// Voice message
+    Voice {
+        voice: &'a Voice,
+        caption: Option<&'a str>,
+        caption_entities: &'a [Box<MessageEntity>],
+    },
+    /// Video message
+    VideoNote(&'a VideoNote),
+    /// Shared contact
+    Contact(&'a Contact),
+    /// Dice with random value
+    Dice(&'a Dice),
+    /// Native poll
+    Poll(&'a Poll),
+    /// Shared location
+    Location(&'a Location),
+    /// Venue, its location is a part of the venue
+    Venue(&'a Venue),
+    /// Invoice for a payment
+    Invoice(&'a Invoice),
+    /// Service message: new members were added to the group or supergroup
+    NewChatMembers(&'a [Box<User>]),
+    /// Service message: a member was removed from the group
+    LeftChatMember(&'a User),
+    /// Service message: the chat title was changed to this value
+    NewChatTitle(&'a str),
+    /// Service message: the chat photo was changed to this value
+    NewChatPhoto(&'a [Box<PhotoSize>]),
+    /// Service message: the chat photo was deleted
+    DeleteChatPhoto,
+    /// Service message: the group has been created
+    GroupChatCreated,
+    /// Service message: the supergroup has been created
+    SupergroupChatCreated,
+    /// Service message: the channel has been created
+    ChannelChatCreated,
+    /// Service message: auto-delete timer settings changed in the chat
+    MessageAutoDeleteTimerChanged(&'a MessageAutoDeleteTimerChanged),
+    /// Service message: the group has been migrated to a supergroup with the
+    /// specified identifier
+    MigrateToChatId(ChatId),
+    /// Service message: the supergroup has been migrated from a group with
+    /// the specified identifier
+    MigrateFromChatId(ChatId),
+    /// Service message: the message was pinned
+    PinnedMessage(&'a Message),
+    /// Service message: a successful payment
+    SuccessfulPayment(&'a SuccessfulPayment),
+    /// Service message: the user has logged in on the website with this
+    /// domain name
+    ConnectedWebsite(&'a str),
+    /// Telegram Passport data
+    PassportData(&'a PassportData),
+    /// Service message: a user in the chat triggered another user's proximity
+    /// alert while sharing Live Location
+    ProximityAlertTriggered(&'a ProximityAlertTriggered),
+    /// Service message: voice chat scheduled
+    VoiceChatScheduled(&'a VoiceChatScheduled),
+    /// Service message: voice chat started
+    VoiceChatStarted(&'a VoiceChatStarted),
+    /// Service message: voice chat ended
+    VoiceChatEnded(&'a VoiceChatEnded),
+    /// Service message: new participants invited to a voice chat
+    VoiceChatParticipantsInvited(&'a VoiceChatParticipantsInvited),
+    /// Content this crate doesn't model yet
+    Unknown,
+}
+
+impl<'a> MessageKind<'a> {
+    /// True, if this is a service message rather than a content sent by a user
+    pub fn is_service(&self) -> bool {
+        matches!(
+            *self,
+            MessageKind::NewChatMembers(_)
+                | MessageKind::LeftChatMember(_)
+                | MessageKind::NewChatTitle(_)
+                | MessageKind::NewChatPhoto(_)
+                | MessageKind::DeleteChatPhoto
+                | MessageKind::GroupChatCreated
+                | MessageKind::SupergroupChatCreated
+                | MessageKind::ChannelChatCreated
+                | MessageKind::MessageAutoDeleteTimerChanged(_)
+                | MessageKind::MigrateToChatId(_)
+                | MessageKind::MigrateFromChatId(_)
+                | MessageKind::PinnedMessage(_)
+                | MessageKind::SuccessfulPayment(_)
+                | MessageKind::ConnectedWebsite(_)
+                | MessageKind::ProximityAlertTriggered(_)
+                | MessageKind::VoiceChatScheduled(_)
+                | MessageKind::VoiceChatStarted(_)
+                | MessageKind::VoiceChatEnded(_)
+                | MessageKind::VoiceChatParticipantsInvited(_)
+        )
+    }
+}
+
+fn slice<T>(items: &Option<Vec<T>>) -> &[T] {
+    items.as_ref().map_or(&[], Vec::as_slice)
+}
+
+impl Message {
+    /// Part of the text or the caption the entity covers. The offsets of
+    /// entities count UTF-16 code units, see `MessageEntity::byte_range`.
+    pub fn entity_text(&self, entity: &MessageEntity) -> Option<&str> {
+        entity.text_in(self.text.as_ref().or(self.caption.as_ref())?)
+    }
+
+    /// Entities of the text and the caption together with the parts they
+    /// cover. Entities that don't fit into their text are skipped.
+    pub fn entity_texts(&self) -> impl Iterator<Item = (&MessageEntity, &str)> {
+        let text = self.text.as_deref().unwrap_or_default();
+        let caption = self.caption.as_deref().unwrap_or_default();
+        let entities = slice(&self.entities).iter().map(move |e| (e, text));
+        let caption_entities = slice(&self.caption_entities)
+            .iter()
+            .map(move |e| (e, caption));
+
+        entities
+            .chain(caption_entities)
+            .filter_map(|(entity, text)| Some((&**entity, entity.text_in(text)?)))
+    }
+
+    /// Borrows the content or the service event of the message
+    pub fn kind(&self) -> MessageKind<'_> {
+        let caption = self.caption.as_deref();
+        let caption_entities = slice(&self.caption_entities);
+
+        if let Some(ref text) = self.text {
+            return MessageKind::Text {
+                text,
+                entities: slice(&self.entities),
+            };
+        }
+        if let Some(ref animation) = self.animation {
+            return MessageKind::Animation {
+                animation,
+                caption,
+                caption_entities,
+            };
+        }
+        if let Some(ref audio) = self.audio {
+            return MessageKind::Audio {
+                audio,
+                caption,
+                caption_entities,
+            };
+        }
+        if let Some(ref document) = self.document {
+            return MessageKind::Document {
+                document,
+                caption,
+                caption_entities,
+            };
+        }
+        if let Some(ref game) = self.game {
+            return MessageKind::Game(game);
+        }
+        if let Some(ref photo) = self.photo {
+            return MessageKind::Photo {
+                photo,
+                caption,
+                caption_entities,
+            };
+        }
+        if let Some(ref sticker) = self.sticker {
+            return MessageKind::Sticker(sticker);
+        }
+        if let Some(ref video) = self.video {
+            return MessageKind::Video {
+                video,
+                caption,
+                caption_entities,
+            };
+        }
+        if let Some(ref voice) = self.voice {
+            return MessageKind::Voice {
+                voice,
+                caption,
+                caption_entities,
+            };
+        }
+        if let Some(ref video_note) = self.video_note {
+            return MessageKind::VideoNote(video_note);
+        }
+        if let Some(ref contact) = self.contact {
+            return MessageKind::Contact(contact);
+        }
+        if let Some(ref dice) = self.dice {
+            return MessageKind::Dice(dice);
+        }
+        if let Some(ref poll) = self.poll {
+            return MessageKind::Poll(poll);
+        }
+        if let Some(ref venue) = self.venue {
+            return MessageKind::Venue(venue);
+        }
+        if let Some(ref location) = self.location {
+            return MessageKind::Location(location);
+        }
+        if let Some(ref invoice) = self.invoice {
+            return MessageKind::Invoice(invoice);
+        }
+        if let Some(ref users) = self.new_chat_members {
+            return MessageKind::NewChatMembers(users);
+        }
+        if let Some(ref user) = self.left_chat_member {
+            return MessageKind::LeftChatMember(user);
+        }
+        if let Some(ref title) = self.new_chat_title {
+            return MessageKind::NewChatTitle(title);
+        }
+        if let Some(ref photo) = self.new_chat_photo {
+            return MessageKind::NewChatPhoto(photo);
+        }
+        if self.delete_chat_photo == Some(true) {
+            return MessageKind::DeleteChatPhoto;
+        }
+        if self.group_chat_created == Some(true) {
+            return MessageKind::GroupChatCreated;
+        }
+        if self.supergroup_chat_created == Some(true) {
+            return MessageKind::SupergroupChatCreated;
+        }
+        if self.channel_chat_created == Some(true) {
+            return MessageKind::ChannelChatCreated;
+        }
+        if let Some(ref timer) = self.message_auto_delete_timer_changed {
+            return MessageKind::MessageAutoDeleteTimerChanged(timer);
+        }
+        if let Some(id) = self.migrate_to_chat_id {
+            return MessageKind::MigrateToChatId(id);
+        }
+        if let Some(id) = self.migrate_from_chat_id {
+            return MessageKind::MigrateFromChatId(id);
+        }
+        if let Some(ref message) = self.pinned_message {
+            return MessageKind::PinnedMessage(message);
+        }
+        if let Some(ref payment) = self.successful_payment {
+            return MessageKind::SuccessfulPayment(payment);
+        }
+        if let Some(ref website) = self.connected_website {
+            return MessageKind::ConnectedWebsite(website);
+        }
+        if let Some(ref data) = self.passport_data {
+            return MessageKind::PassportData(data);
+        }
+        if let Some(ref alert) = self.proximity_alert_triggered {
+            return MessageKind::ProximityAlertTriggered(alert);
+        }
+        if let Some(ref scheduled) = self.voice_chat_scheduled {
+            return MessageKind::VoiceChatScheduled(scheduled);
+        }
+        if let Some(ref started) = self.voice_chat_started {
+            return MessageKind::VoiceChatStarted(started);
+        }
+        if let Some(ref ended) = self.voice_chat_ended {
+            return MessageKind::VoiceChatEnded(ended);
+        }
+        if let Some(ref invited) = self.voice_chat_participants_invited {
+            return MessageKind::VoiceChatParticipantsInvited(invited);
+        }
+
+        MessageKind::Unknown
+    }
+}
@@ -0,0 +1,223 @@
+use std::iter;
+use std::mem;
+use std::ops::Range;
+
+use serde::de::{DeserializeOwned, Error};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{self, Map, Value};
+
+use {MessageEntity, User};
+
+/// Type of the entity together with the data that only makes sense for
+/// that type. Entity types added to the Bot API later are kept in `Unknown`.
+/// See https://core.telegram.org/bots/api#messageentity
+#[derive(Debug, Clone)]
+pub enum MessageEntityKind {
+    /// “mention” (@username)
+    Mention,
+    /// “hashtag” (#hashtag)
+    Hashtag,
+    /// “cashtag” ($USD)
+    Cashtag,
+    /// “bot_command” (/start@jobs_bot)
+    BotCommand,
+    /// “url” (https://telegram.org)
+    Url,
+    /// “email” (do-not-reply@telegram.org)
+    Email,
+    /// “phone_number” (+1-212-555-0123)
+    PhoneNumber,
+    /// “bold” (bold text)
+    Bold,
+    /// “italic” (italic text)
+    Italic,
+    /// “underline” (underlined text)
+    Underline,
+    /// “strikethrough” (strikethrough text)
+    Strikethrough,
+    /// “code” (monowidth string)
+    Code,
+    /// “pre” (monowidth block) with the programming language of the entity text
+    Pre { language: Option<String> },
+    /// “text_link” (for clickable text URLs) with the url that will be opened
+    /// after user taps on the text
+    TextLink { url: String },
+    /// “text_mention” (for users without usernames) with the mentioned user
+    TextMention { user: Box<User> },
+    /// Any other entity type, as sent by Telegram, with the rest of the
+    /// fields of the entity, e.g. the `custom_emoji_id` of a “custom_emoji”
+    Unknown {
+        type_tl: String,
+        fields: Map<String, Value>,
+    },
+}
+
+impl MessageEntityKind {
+    /// Value of the `type` field as sent by Telegram
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageEntityKind::Mention => "mention",
+            MessageEntityKind::Hashtag => "hashtag",
+            MessageEntityKind::Cashtag => "cashtag",
+            MessageEntityKind::BotCommand => "bot_command",
+            MessageEntityKind::Url => "url",
+            MessageEntityKind::Email => "email",
+            MessageEntityKind::PhoneNumber => "phone_number",
+            MessageEntityKind::Bold => "bold",
+            MessageEntityKind::Italic => "italic",
+            MessageEntityKind::Underline => "underline",
+            MessageEntityKind::Strikethrough => "strikethrough",
+            MessageEntityKind::Code => "code",
+            MessageEntityKind::Pre { .. } => "pre",
+            MessageEntityKind::TextLink { .. } => "text_link",
+            MessageEntityKind::TextMention { .. } => "text_mention",
+            MessageEntityKind::Unknown { ref type_tl, .. } => type_tl,
+        }
+    }
+}
+
+impl MessageEntity {
+    /// Range of bytes of the text the entity covers.
+    ///
+    /// `offset` and `length` count UTF-16 code units, so each character out
+    /// of the Basic Multilingual Plane, like most emoji, is two units long.
+    /// None, if the entity doesn't fit into the text or splits a character.
+    pub fn byte_range(&self, text: &str) -> Option<Range<usize>> {
+        if self.offset < 0 || self.length < 0 {
+            return None;
+        }
+
+        let offset = self.offset as usize;
+        let end = offset.checked_add(self.length as usize)?;
+        let mut start = None;
+        let mut units = 0;
+
+        // The end of the text is a boundary too
+        for (index, c) in text.char_indices().chain(iter::once((text.len(), '\0'))) {
+            if units == offset {
+                start = Some(index);
+            }
+            if units == end {
+                return start.map(|start| start..index);
+            }
+            if units > end {
+                return None;
+            }
+            units += c.len_utf16();
+        }
+
+        None
+    }
+
+    /// Part of the text the entity covers, see `byte_range`
+    pub fn text_in<'a>(&self, text: &'a str) -> Option<&'a str> {
+        self.byte_range(text).map(|range| &text[range])
+    }
+}
+
+/// Removes the field from an object, a null is the same as no field at all
+pub(crate) fn take<T: DeserializeOwned>(
+    fields: &mut Map<String, Value>,
+    key: &str,
+) -> Result<Option<T>, serde_json::Error> {
+    match fields.remove(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => serde_json::from_value(value).map(Some),
+    }
+}
+
+fn take_required<T: DeserializeOwned>(
+    fields: &mut Map<String, Value>,
+    key: &'static str,
+) -> Result<T, serde_json::Error> {
+    take(fields, key)?.ok_or_else(|| serde_json::Error::missing_field(key))
+}
+
+impl MessageEntityKind {
+    /// Takes the fields of the kind out of a MessageEntity object, all of the
+    /// remaining ones if the type is unknown
+    fn take_from(fields: &mut Map<String, Value>) -> Result<Self, serde_json::Error> {
+        let type_tl: String = take_required(fields, "type")?;
+
+        Ok(match type_tl.as_str() {
+            "mention" => MessageEntityKind::Mention,
+            "hashtag" => MessageEntityKind::Hashtag,
+            "cashtag" => MessageEntityKind::Cashtag,
+            "bot_command" => MessageEntityKind::BotCommand,
+            "url" => MessageEntityKind::Url,
+            "email" => MessageEntityKind::Email,
+            "phone_number" => MessageEntityKind::PhoneNumber,
+            "bold" => MessageEntityKind::Bold,
+            "italic" => MessageEntityKind::Italic,
+            "underline" => MessageEntityKind::Underline,
+            "strikethrough" => MessageEntityKind::Strikethrough,
+            "code" => MessageEntityKind::Code,
+            "pre" => MessageEntityKind::Pre {
+                language: take(fields, "language")?,
+            },
+            "text_link" => MessageEntityKind::TextLink {
+                url: take_required(fields, "url")?,
+            },
+            "text_mention" => MessageEntityKind::TextMention {
+                user: take_required(fields, "user")?,
+            },
+            _ => MessageEntityKind::Unknown {
+                type_tl,
+                fields: mem::replace(fields, Map::new()),
+            },
+        })
+    }
+}
+
+/// Flat inside of a MessageEntity object: `type` and the fields of the kind
+impl Serialize for MessageEntityKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry("type", self.as_str())?;
+        match *self {
+            MessageEntityKind::Pre {
+                language: Some(ref language),
+            } => map.serialize_entry("language", language)?,
+            MessageEntityKind::TextLink { ref url } => map.serialize_entry("url", url)?,
+            MessageEntityKind::TextMention { ref user } => map.serialize_entry("user", user)?,
+            MessageEntityKind::Unknown { ref fields, .. } => {
+                for (key, value) in fields {
+                    map.serialize_entry(key, value)?;
+                }
+            }
+            _ => (),
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageEntityKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fields = Map::deserialize(deserializer)?;
+
+        MessageEntityKind::take_from(&mut fields).map_err(D::Error::custom)
+    }
+}
+
+/// The kind can't be flattened on deserialization: the fields of an unknown
+/// type would go to both the kind and `extra`
+impl<'de> Deserialize<'de> for MessageEntity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Map::deserialize(deserializer)?;
+
+        entity_from(fields).map_err(D::Error::custom)
+    }
+}
+
+fn entity_from(mut fields: Map<String, Value>) -> Result<MessageEntity, serde_json::Error> {
+    Ok(MessageEntity {
+        offset: take_required(&mut fields, "offset")?,
+        length: take_required(&mut fields, "length")?,
+        kind: MessageEntityKind::take_from(&mut fields)?,
+        #[cfg(feature = "extra-fields")]
+        extra: fields.into_iter().collect(),
+    })
+}
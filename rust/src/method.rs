@@ -0,0 +1,75 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use {Message, User, WebhookInfo};
+
+/// A Bot API method: the struct is the request payload, sent to
+/// `https://api.telegram.org/bot<token>/<NAME>`.
+///
+/// ``` ignore
+/// fn call<M: Method>(method: &M) -> Result<M::Response, Error> {
+///     let url = format!("https://api.telegram.org/bot{}/{}", token, M::NAME);
+///     ...
+/// }
+/// ```
+pub trait Method: Serialize {
+    /// Name of the method as it appears in the URL, e.g. `sendMessage`
+    const NAME: &'static str;
+
+    /// What the method returns on success
+    type Response: DeserializeOwned;
+}
+
+/// Result of the methods that return the edited Message, or True if the
+/// message was sent via the bot (an inline message)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum MessageOrBool {
+    Message(Box<Message>),
+    Bool(bool),
+}
+
+/// A simple method for testing your bot's auth token. Requires no
+/// parameters. Returns basic information about the bot in form of a User
+/// object.
+/// See https://core.telegram.org/bots/api#getme
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GetMe {}
+
+impl Method for GetMe {
+    const NAME: &'static str = "getMe";
+    type Response = User;
+}
+
+/// Use this method to log out from the cloud Bot API server before launching
+/// the bot locally. Requires no parameters. Returns True on success.
+/// See https://core.telegram.org/bots/api#logout
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LogOut {}
+
+impl Method for LogOut {
+    const NAME: &'static str = "logOut";
+    type Response = bool;
+}
+
+/// Use this method to close the bot instance before moving it from one local
+/// server to another. Requires no parameters. Returns True on success.
+/// See https://core.telegram.org/bots/api#close
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Close {}
+
+impl Method for Close {
+    const NAME: &'static str = "close";
+    type Response = bool;
+}
+
+/// Use this method to get current webhook status. Requires no parameters.
+/// On success, returns a WebhookInfo object.
+/// See https://core.telegram.org/bots/api#getwebhookinfo
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GetWebhookInfo {}
+
+impl Method for GetWebhookInfo {
+    const NAME: &'static str = "getWebhookInfo";
+    type Response = WebhookInfo;
+}
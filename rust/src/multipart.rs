@@ -0,0 +1,762 @@
+//! Encoding of method payloads for the HTTP request.
+//!
+//! Telegram accepts JSON unless a parameter is a new file, in which case the
+//! whole request has to be sent as multipart/form-data. There every
+//! top-level field becomes a part of its own: scalars as plain text, nested
+//! objects and arrays as JSON and uploads as files.
+
+use std::collections::hash_map::RandomState;
+use std::error;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+
+use serde::ser::{self, Impossible, Serialize, Serializer};
+use serde_json;
+
+use input_file::UPLOAD;
+
+/// Body of a request to the Bot API
+#[derive(Debug, Clone)]
+pub enum RequestBody {
+    /// Payload without uploads, sent as application/json
+    Json(Vec<u8>),
+    /// Payload with at least one upload
+    Multipart(Multipart),
+}
+
+impl RequestBody {
+    /// Encodes a method payload, choosing JSON when nothing needs uploading
+    pub fn new<T: Serialize + ?Sized>(request: &T) -> Result<RequestBody, EncodeError> {
+        RequestBody::with_boundary(request, &random_boundary())
+    }
+
+    /// Same as `new`, but separates the parts of a multipart body with the
+    /// given boundary
+    pub fn with_boundary<T: Serialize + ?Sized>(
+        request: &T,
+        boundary: &str,
+    ) -> Result<RequestBody, EncodeError> {
+        RequestBody::encode(request, boundary, Vec::new())
+    }
+
+    /// Encodes a method payload along with the files its fields refer to as
+    /// `attach://<name>`, see `attach_media`
+    pub fn with_attachments<T: Serialize + ?Sized>(
+        request: &T,
+        attachments: Vec<Part>,
+    ) -> Result<RequestBody, EncodeError> {
+        RequestBody::encode(request, &random_boundary(), attachments)
+    }
+
+    fn encode<T: Serialize + ?Sized>(
+        request: &T,
+        boundary: &str,
+        attachments: Vec<Part>,
+    ) -> Result<RequestBody, EncodeError> {
+        let mut form = Multipart::with_boundary(boundary);
+
+        request.serialize(FormSerializer { form: &mut form })?;
+        form.parts.extend(attachments);
+
+        if let Some(mime_type) = form
+            .parts
+            .iter()
+            .filter_map(|part| part.mime_type.as_ref())
+            .find(|mime_type| !is_valid_mime_type(mime_type))
+        {
+            return Err(EncodeError::InvalidMimeType(mime_type.clone()));
+        }
+
+        if form.parts.iter().any(Part::is_file) {
+            Ok(RequestBody::Multipart(form))
+        } else {
+            Ok(RequestBody::Json(serde_json::to_vec(request)?))
+        }
+    }
+
+    /// Value of the Content-Type header
+    pub fn content_type(&self) -> String {
+        match *self {
+            RequestBody::Json(_) => "application/json".to_owned(),
+            RequestBody::Multipart(ref form) => form.content_type(),
+        }
+    }
+
+    /// Bytes to send as the body of the request
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            RequestBody::Json(bytes) => bytes,
+            RequestBody::Multipart(form) => form.to_bytes(),
+        }
+    }
+}
+
+/// A multipart/form-data body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Multipart {
+    boundary: String,
+    parts: Vec<Part>,
+}
+
+impl Multipart {
+    /// An empty body with a random boundary
+    pub fn new() -> Multipart {
+        Multipart::with_boundary(random_boundary())
+    }
+
+    /// An empty body with the given boundary
+    pub fn with_boundary<B: Into<String>>(boundary: B) -> Multipart {
+        Multipart {
+            boundary: boundary.into(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Delimiter of the parts, without the leading dashes
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Parts in the order they are written
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Appends a part to the body
+    pub fn push(&mut self, part: Part) {
+        self.parts.push(part);
+    }
+
+    /// Value of the Content-Type header
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Writes the body out
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+
+        for part in &self.parts {
+            body.extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"",
+                    escape(&part.name)
+                )
+                .as_bytes(),
+            );
+            if let Some(ref filename) = part.filename {
+                body.extend_from_slice(format!("; filename=\"{}\"", escape(filename)).as_bytes());
+                body.extend_from_slice(
+                    format!(
+                        "\r\nContent-Type: {}",
+                        escape(
+                            part.mime_type
+                                .as_deref()
+                                .unwrap_or("application/octet-stream")
+                        )
+                    )
+                    .as_bytes(),
+                );
+            }
+            body.extend_from_slice(b"\r\n\r\n");
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+
+        body
+    }
+}
+
+impl Default for Multipart {
+    fn default() -> Multipart {
+        Multipart::new()
+    }
+}
+
+/// A single field of a multipart body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    /// Name of the form field
+    pub name: String,
+    /// Name of the file, set for uploads only
+    pub filename: Option<String>,
+    /// MIME type of the file, `application/octet-stream` is sent if unknown
+    pub mime_type: Option<String>,
+    /// Contents of the field
+    pub data: Vec<u8>,
+}
+
+impl Part {
+    /// A plain text field
+    pub fn text<N: Into<String>, V: Into<String>>(name: N, value: V) -> Part {
+        Part {
+            name: name.into(),
+            filename: None,
+            mime_type: None,
+            data: value.into().into_bytes(),
+        }
+    }
+
+    /// A file field
+    pub fn file<N: Into<String>, F: Into<String>, D: Into<Vec<u8>>>(
+        name: N,
+        filename: F,
+        data: D,
+        mime_type: Option<String>,
+    ) -> Part {
+        Part {
+            name: name.into(),
+            filename: Some(filename.into()),
+            mime_type,
+            data: data.into(),
+        }
+    }
+
+    /// True, if the part carries a file
+    pub fn is_file(&self) -> bool {
+        self.filename.is_some()
+    }
+}
+
+/// Error raised while encoding a request
+#[derive(Debug)]
+pub enum EncodeError {
+    /// Only structs and maps can be sent as a request
+    NotAnObject,
+    /// A nested value couldn't be written as JSON, e.g. it has an upload
+    /// that wasn't replaced with an `attach://` reference
+    Json(serde_json::Error),
+    /// Error raised by the `Serialize` implementation of a field
+    Custom(String),
+    /// MIME type of an upload has a quote or a control character, so it
+    /// can't be written into the part header
+    InvalidMimeType(String),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncodeError::NotAnObject => f.write_str("request must be a struct or a map"),
+            EncodeError::Json(ref e) => write!(f, "can't write a field as JSON: {}", e),
+            EncodeError::Custom(ref msg) => f.write_str(msg),
+            EncodeError::InvalidMimeType(ref mime_type) => {
+                write!(f, "invalid MIME type of an upload: {:?}", mime_type)
+            }
+        }
+    }
+}
+
+impl error::Error for EncodeError {}
+
+impl ser::Error for EncodeError {
+    fn custom<T: fmt::Display>(msg: T) -> EncodeError {
+        EncodeError::Custom(msg.to_string())
+    }
+}
+
+impl From<serde_json::Error> for EncodeError {
+    fn from(e: serde_json::Error) -> EncodeError {
+        EncodeError::Json(e)
+    }
+}
+
+fn random_boundary() -> String {
+    let state = RandomState::new();
+    let mut first = state.build_hasher();
+    let mut second = state.build_hasher();
+
+    first.write_u8(0);
+    second.write_u8(1);
+
+    format!("{:016x}{:016x}", first.finish(), second.finish())
+}
+
+/// Percent-encodes the characters that would end a quoted header parameter
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// True, if the value can be written as is after `Content-Type: `
+fn is_valid_mime_type(value: &str) -> bool {
+    !value.chars().any(|c| c == '"' || c.is_control())
+}
+
+/// Splits the top-level object into parts
+struct FormSerializer<'a> {
+    form: &'a mut Multipart,
+}
+
+impl<'a> FormSerializer<'a> {
+    fn push_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: String,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        let part = match value.serialize(FieldSerializer)? {
+            Field::Skip => return Ok(()),
+            Field::Text(text) => Part::text(name, text),
+            Field::Upload(upload) => {
+                Part::file(name, upload.filename, upload.data, upload.mime_type)
+            }
+            Field::Bytes(_) | Field::Nested => Part::text(name, serde_json::to_string(value)?),
+        };
+
+        self.form.push(part);
+
+        Ok(())
+    }
+}
+
+macro_rules! not_an_object {
+    ($($method:ident($($arg:ty),*),)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<(), EncodeError> {
+                Err(EncodeError::NotAnObject)
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for FormSerializer<'a> {
+    type Ok = ();
+    type Error = EncodeError;
+    type SerializeSeq = Impossible<(), EncodeError>;
+    type SerializeTuple = Impossible<(), EncodeError>;
+    type SerializeTupleStruct = Impossible<(), EncodeError>;
+    type SerializeTupleVariant = Impossible<(), EncodeError>;
+    type SerializeMap = FormMap<'a>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), EncodeError>;
+
+    not_an_object! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), EncodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), EncodeError> {
+        Err(EncodeError::NotAnObject)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, EncodeError> {
+        Err(EncodeError::NotAnObject)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, EncodeError> {
+        Err(EncodeError::NotAnObject)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, EncodeError> {
+        Err(EncodeError::NotAnObject)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, EncodeError> {
+        Err(EncodeError::NotAnObject)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<FormMap<'a>, EncodeError> {
+        Ok(FormMap {
+            form: self,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, EncodeError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, EncodeError> {
+        Err(EncodeError::NotAnObject)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl<'a> ser::SerializeStruct for FormSerializer<'a> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.push_field(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        Ok(())
+    }
+}
+
+/// Top-level map, e.g. a struct with a flattened field
+struct FormMap<'a> {
+    form: FormSerializer<'a>,
+    key: Option<String>,
+}
+
+impl<'a> ser::SerializeMap for FormMap<'a> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), EncodeError> {
+        match key.serialize(FieldSerializer)? {
+            Field::Text(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(EncodeError::Custom("map keys must be strings".to_owned())),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        let key = self.key.take().unwrap_or_default();
+
+        self.form.push_field(key, value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        Ok(())
+    }
+}
+
+/// What a single top-level field turned out to be
+enum Field {
+    /// None, left out of the form
+    Skip,
+    Text(String),
+    Upload(Upload),
+    Bytes(Vec<u8>),
+    /// Object or array, written as JSON
+    Nested,
+}
+
+#[derive(Default)]
+struct Upload {
+    filename: String,
+    data: Vec<u8>,
+    mime_type: Option<String>,
+}
+
+/// Tells scalars, uploads and nested values apart
+struct FieldSerializer;
+
+impl Serializer for FieldSerializer {
+    type Ok = Field;
+    type Error = EncodeError;
+    type SerializeSeq = Nested;
+    type SerializeTuple = Nested;
+    type SerializeTupleStruct = Nested;
+    type SerializeTupleVariant = Nested;
+    type SerializeMap = Nested;
+    type SerializeStruct = FieldStruct;
+    type SerializeStructVariant = Nested;
+
+    fn serialize_bool(self, v: bool) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Field, EncodeError> {
+        Ok(Field::Text(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Field, EncodeError> {
+        Ok(Field::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Field, EncodeError> {
+        Ok(Field::Skip)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Field, EncodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Field, EncodeError> {
+        Ok(Field::Skip)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Field, EncodeError> {
+        Ok(Field::Skip)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Field, EncodeError> {
+        Ok(Field::Text(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Field, EncodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Field, EncodeError> {
+        Ok(Field::Nested)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Nested, EncodeError> {
+        Ok(Nested)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Nested, EncodeError> {
+        Ok(Nested)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Nested, EncodeError> {
+        Ok(Nested)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Nested, EncodeError> {
+        Ok(Nested)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Nested, EncodeError> {
+        Ok(Nested)
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<FieldStruct, EncodeError> {
+        if name == UPLOAD {
+            Ok(FieldStruct::Upload(Upload::default()))
+        } else {
+            Ok(FieldStruct::Nested)
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Nested, EncodeError> {
+        Ok(Nested)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Compound value that is written as JSON later, its items are ignored
+struct Nested;
+
+macro_rules! nested {
+    ($($trait:ident::$method:ident,)*) => {
+        $(
+            impl ser::$trait for Nested {
+                type Ok = Field;
+                type Error = EncodeError;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), EncodeError> {
+                    Ok(())
+                }
+
+                fn end(self) -> Result<Field, EncodeError> {
+                    Ok(Field::Nested)
+                }
+            }
+        )*
+    };
+}
+
+nested! {
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+}
+
+impl ser::SerializeMap for Nested {
+    type Ok = Field;
+    type Error = EncodeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, _key: &T) -> Result<(), EncodeError> {
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), EncodeError> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Field, EncodeError> {
+        Ok(Field::Nested)
+    }
+}
+
+impl ser::SerializeStructVariant for Nested {
+    type Ok = Field;
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        _value: &T,
+    ) -> Result<(), EncodeError> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Field, EncodeError> {
+        Ok(Field::Nested)
+    }
+}
+
+/// A struct field is either an upload, collected here, or a nested object
+enum FieldStruct {
+    Upload(Upload),
+    Nested,
+}
+
+impl ser::SerializeStruct for FieldStruct {
+    type Ok = Field;
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        let upload = match *self {
+            FieldStruct::Upload(ref mut upload) => upload,
+            FieldStruct::Nested => return Ok(()),
+        };
+
+        match (key, value.serialize(FieldSerializer)?) {
+            ("filename", Field::Text(filename)) => upload.filename = filename,
+            ("mime_type", Field::Text(mime_type)) => upload.mime_type = Some(mime_type),
+            ("data", Field::Bytes(data)) => upload.data = data,
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Field, EncodeError> {
+        Ok(match self {
+            FieldStruct::Upload(upload) => Field::Upload(upload),
+            FieldStruct::Nested => Field::Nested,
+        })
+    }
+}
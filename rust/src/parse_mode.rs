@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// Mode for parsing entities in the message text or media caption.
+/// See https://core.telegram.org/bots/api#formatting-options
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Legacy Markdown style, kept by Telegram for backward compatibility
+    /// See https://core.telegram.org/bots/api#markdown-style
+    Markdown,
+    /// See https://core.telegram.org/bots/api#markdownv2-style
+    MarkdownV2,
+    /// See https://core.telegram.org/bots/api#html-style
+    #[serde(rename = "HTML")]
+    Html,
+}
+
+impl ParseMode {
+    /// Value of the `parse_mode` parameter as expected by Telegram
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ParseMode::Markdown => "Markdown",
+            ParseMode::MarkdownV2 => "MarkdownV2",
+            ParseMode::Html => "HTML",
+        }
+    }
+}
+
+impl fmt::Display for ParseMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,137 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use {ChatId, UserId};
+
+/// Target of a method taking a `chat_id`: a chat identifier, or the username
+/// of a channel or a supergroup in the format `@channelusername`.
+///
+/// Serialized as a bare number or an `@username` string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Recipient {
+    /// Unique identifier of the chat
+    Id(ChatId),
+    /// Username of the channel or the supergroup, without the leading `@`
+    Username(String),
+}
+
+impl Recipient {
+    /// A public channel or supergroup, `@` is optional.
+    ///
+    /// Usernames are 5-32 characters long, consist of latin letters, digits
+    /// and underscores, start with a letter and don't end with an underscore.
+    pub fn username(username: &str) -> Result<Recipient, InvalidUsername> {
+        let name = username.strip_prefix('@').unwrap_or(username);
+        let valid = (5..=32).contains(&name.len())
+            && name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && !name.ends_with('_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if valid {
+            Ok(Recipient::Username(name.to_owned()))
+        } else {
+            Err(InvalidUsername(username.to_owned()))
+        }
+    }
+}
+
+impl From<ChatId> for Recipient {
+    fn from(id: ChatId) -> Recipient {
+        Recipient::Id(id)
+    }
+}
+
+impl From<UserId> for Recipient {
+    fn from(id: UserId) -> Recipient {
+        Recipient::Id(id.into())
+    }
+}
+
+impl From<i64> for Recipient {
+    fn from(id: i64) -> Recipient {
+        Recipient::Id(ChatId(id))
+    }
+}
+
+/// Numbers are identifiers, anything else must be an `@username`
+impl FromStr for Recipient {
+    type Err = InvalidUsername;
+
+    fn from_str(value: &str) -> Result<Recipient, InvalidUsername> {
+        if let Ok(id) = value.parse() {
+            return Ok(Recipient::Id(ChatId(id)));
+        }
+        if !value.starts_with('@') {
+            return Err(InvalidUsername(value.to_owned()));
+        }
+
+        Recipient::username(value)
+    }
+}
+
+impl fmt::Display for Recipient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Recipient::Id(ref id) => id.fmt(f),
+            Recipient::Username(ref name) => write!(f, "@{}", name),
+        }
+    }
+}
+
+impl Serialize for Recipient {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Recipient::Id(ref id) => id.serialize(serializer),
+            Recipient::Username(ref name) => serializer.collect_str(&format_args!("@{}", name)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Recipient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RecipientVisitor;
+
+        impl<'de> Visitor<'de> for RecipientVisitor {
+            type Value = Recipient;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a chat identifier or an @username")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Recipient, E> {
+                Ok(Recipient::from(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Recipient, E> {
+                if value > i64::MAX as u64 {
+                    return Err(E::invalid_value(de::Unexpected::Unsigned(value), &self));
+                }
+                Ok(Recipient::from(value as i64))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Recipient, E> {
+                value
+                    .parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(RecipientVisitor)
+    }
+}
+
+/// A string that is neither a chat identifier nor a valid `@username`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidUsername(pub String);
+
+impl fmt::Display for InvalidUsername {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid username: {:?}", self.0)
+    }
+}
+
+impl error::Error for InvalidUsername {}
@@ -0,0 +1,87 @@
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer};
+use serde_json::{self, Map, Value};
+
+use {ForceReply, InlineKeyboardMarkup, ReplyKeyboardMarkup, ReplyKeyboardRemove};
+
+/// Additional interface options of a sent message: an inline keyboard, a
+/// custom reply keyboard, instructions to remove the reply keyboard or to
+/// force a reply from the user.
+/// See https://core.telegram.org/bots#keyboards
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ReplyMarkup {
+    InlineKeyboardMarkup(Box<InlineKeyboardMarkup>),
+    ReplyKeyboardMarkup(Box<ReplyKeyboardMarkup>),
+    ReplyKeyboardRemove(Box<ReplyKeyboardRemove>),
+    ForceReply(Box<ForceReply>),
+}
+
+impl From<InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: InlineKeyboardMarkup) -> ReplyMarkup {
+        ReplyMarkup::InlineKeyboardMarkup(Box::new(markup))
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardMarkup) -> ReplyMarkup {
+        ReplyMarkup::ReplyKeyboardMarkup(Box::new(markup))
+    }
+}
+
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardRemove) -> ReplyMarkup {
+        ReplyMarkup::ReplyKeyboardRemove(Box::new(markup))
+    }
+}
+
+impl From<ForceReply> for ReplyMarkup {
+    fn from(markup: ForceReply) -> ReplyMarkup {
+        ReplyMarkup::ForceReply(Box::new(markup))
+    }
+}
+
+/// The key each of the objects has and the others don't
+const KEYS: [&str; 4] = [
+    "inline_keyboard",
+    "keyboard",
+    "remove_keyboard",
+    "force_reply",
+];
+
+fn from_value<T, E>(value: Value) -> Result<ReplyMarkup, E>
+where
+    T: DeserializeOwned + Into<ReplyMarkup>,
+    E: Error,
+{
+    serde_json::from_value::<T>(value)
+        .map(Into::into)
+        .map_err(E::custom)
+}
+
+impl<'de> Deserialize<'de> for ReplyMarkup {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Map::deserialize(deserializer)?;
+        let mut keys = KEYS.iter().cloned().filter(|key| fields.contains_key(*key));
+        let key = keys.next();
+
+        if keys.next().is_some() {
+            return Err(D::Error::custom(
+                "reply markup has more than one of `inline_keyboard`, `keyboard`, \
+                 `remove_keyboard` and `force_reply`",
+            ));
+        }
+
+        let value = Value::Object(fields);
+        match key {
+            Some("inline_keyboard") => from_value::<InlineKeyboardMarkup, _>(value),
+            Some("keyboard") => from_value::<ReplyKeyboardMarkup, _>(value),
+            Some("remove_keyboard") => from_value::<ReplyKeyboardRemove, _>(value),
+            Some("force_reply") => from_value::<ForceReply, _>(value),
+            _ => Err(D::Error::custom(
+                "reply markup has none of `inline_keyboard`, `keyboard`, `remove_keyboard` \
+                 and `force_reply`",
+            )),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use std::error;
+use std::fmt;
+use std::time::Duration;
+
+use {ChatId, ResponseParameters};
+
+/// The object every Bot API method answers with. The `result` is there if
+/// `ok` is true, the error is described by the rest of the fields otherwise.
+/// See https://core.telegram.org/bots/api#making-requests
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// True, if the request was successful
+    pub ok: bool,
+
+    /// Result of the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<T>,
+
+    /// Human-readable description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Error code of an unsuccessful request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i64>,
+
+    /// Can help to automatically handle the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Box<ResponseParameters>>,
+}
+
+impl<T> ApiResponse<T> {
+    /// The result of a successful request or the error. The ApiError of an
+    /// unsuccessful request is wrapped in `ResponseError::Api`, next to
+    /// `ResponseError::MissingResult` for a successful one without a result.
+    /// `ResponseError::api_error` gets it back.
+    pub fn into_result(self) -> Result<T, ResponseError> {
+        if !self.ok {
+            return Err(ResponseError::Api(ApiError {
+                error_code: self.error_code.unwrap_or_default(),
+                description: self.description.unwrap_or_default(),
+                parameters: self.parameters,
+            }));
+        }
+
+        self.result.ok_or(ResponseError::MissingResult)
+    }
+}
+
+impl<T> From<ApiResponse<T>> for Result<T, ResponseError> {
+    fn from(response: ApiResponse<T>) -> Result<T, ResponseError> {
+        response.into_result()
+    }
+}
+
+/// Why an ApiResponse has no result. Wraps the ApiError Telegram answered
+/// with, a response that is neither an error nor has a result is an error of
+/// its own.
+#[derive(Debug, Clone)]
+pub enum ResponseError {
+    /// The request was unsuccessful
+    Api(ApiError),
+    /// `ok` is true, but the response has no `result`. Telegram never sends
+    /// this, so the response comes from something else.
+    MissingResult,
+}
+
+impl ResponseError {
+    /// The error Telegram answered with, if there is one
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match *self {
+            ResponseError::Api(ref error) => Some(error),
+            ResponseError::MissingResult => None,
+        }
+    }
+}
+
+impl From<ApiError> for ResponseError {
+    fn from(error: ApiError) -> ResponseError {
+        ResponseError::Api(error)
+    }
+}
+
+impl fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResponseError::Api(ref error) => fmt::Display::fmt(error, f),
+            ResponseError::MissingResult => f.write_str("successful response has no result"),
+        }
+    }
+}
+
+impl error::Error for ResponseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ResponseError::Api(ref error) => Some(error),
+            ResponseError::MissingResult => None,
+        }
+    }
+}
+
+/// An unsuccessful request
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// Error code, mostly the matching HTTP status. Its contents are subject
+    /// to change in the future.
+    pub error_code: i64,
+
+    /// Human-readable description of the error
+    pub description: String,
+
+    /// Can help to automatically handle the error
+    pub parameters: Option<Box<ResponseParameters>>,
+}
+
+impl ApiError {
+    /// Which of the well-known errors this is
+    pub fn kind(&self) -> ApiErrorKind {
+        let parameters = self.parameters.as_ref();
+
+        if let Some(seconds) = parameters.and_then(|p| p.retry_after) {
+            return ApiErrorKind::RetryAfter(Duration::from_secs(seconds.max(0) as u64));
+        }
+        if let Some(chat_id) = parameters.and_then(|p| p.migrate_to_chat_id) {
+            return ApiErrorKind::MigrateToChat(chat_id);
+        }
+
+        ApiErrorKind::parse(self.error_code, &self.description)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (error code {})", self.description, self.error_code)
+    }
+}
+
+impl error::Error for ApiError {}
+
+/// Well-known errors, told apart by the parameters or the description of
+/// the error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiErrorKind {
+    /// Flood control was exceeded, the request can be repeated after this
+    /// time
+    RetryAfter(Duration),
+    /// The group has been migrated to a supergroup with this identifier, the
+    /// request should be sent there
+    MigrateToChat(ChatId),
+    /// The bot token is invalid or was revoked
+    Unauthorized,
+    /// Another getUpdates request is running with the same token
+    TerminatedByOtherGetUpdates,
+    /// getUpdates can't be used while a webhook is set
+    WebhookActive,
+    /// “chat not found”
+    ChatNotFound,
+    /// “user not found”
+    UserNotFound,
+    /// The user has blocked the bot
+    BotBlocked,
+    /// The user has deleted their account
+    UserDeactivated,
+    /// The bot was removed from the group, supergroup or channel
+    BotKicked,
+    /// The bot is not a member of the channel or supergroup
+    BotNotMember,
+    /// The user has never started a conversation with the bot
+    CantInitiateConversation,
+    /// Bots can't send messages to other bots
+    CantMessageBots,
+    /// The bot lacks rights to do this in the chat
+    NotEnoughRights,
+    /// The new content of the message is the same as the current one
+    MessageNotModified,
+    /// “message to edit not found”
+    MessageToEditNotFound,
+    /// “message to delete not found”
+    MessageToDeleteNotFound,
+    /// “message can't be edited”
+    MessageCantBeEdited,
+    /// “message can't be deleted”
+    MessageCantBeDeleted,
+    /// The message to reply to doesn't exist
+    ReplyMessageNotFound,
+    /// “message text is empty”
+    MessageTextEmpty,
+    /// “message is too long”
+    MessageTooLong,
+    /// The text has invalid Markdown or HTML markup
+    CantParseEntities,
+    /// The callback or inline query is too old to be answered
+    QueryTooOld,
+    /// The file_id or the URL of a file is invalid
+    WrongFileIdentifier,
+    /// Any other error
+    Other,
+}
+
+impl ApiErrorKind {
+    /// Classifies an error by its code and description, e.g.
+    /// `(403, "Forbidden: bot was blocked by the user")`
+    pub fn parse(error_code: i64, description: &str) -> ApiErrorKind {
+        // "Bad Request: chat not found" -> "chat not found"
+        let message = match description.find(": ") {
+            Some(index) => &description[index + 2..],
+            None => description,
+        };
+        let starts = |prefix: &str| message.starts_with(prefix);
+
+        if let Some(seconds) = message
+            .strip_prefix("retry after ")
+            .and_then(|seconds| seconds.trim().parse().ok())
+        {
+            return ApiErrorKind::RetryAfter(Duration::from_secs(seconds));
+        }
+
+        if error_code == 401 {
+            ApiErrorKind::Unauthorized
+        } else if starts("terminated by other getUpdates request") {
+            ApiErrorKind::TerminatedByOtherGetUpdates
+        } else if starts("can't use getUpdates method while webhook is active") {
+            ApiErrorKind::WebhookActive
+        } else if starts("chat not found") {
+            ApiErrorKind::ChatNotFound
+        } else if starts("user not found") {
+            ApiErrorKind::UserNotFound
+        } else if starts("bot was blocked by the user") {
+            ApiErrorKind::BotBlocked
+        } else if starts("user is deactivated") {
+            ApiErrorKind::UserDeactivated
+        } else if starts("bot was kicked from") {
+            ApiErrorKind::BotKicked
+        } else if starts("bot is not a member of") {
+            ApiErrorKind::BotNotMember
+        } else if starts("bot can't initiate conversation with a user") {
+            ApiErrorKind::CantInitiateConversation
+        } else if starts("bot can't send messages to bots") {
+            ApiErrorKind::CantMessageBots
+        } else if starts("not enough rights") || starts("have no rights") {
+            ApiErrorKind::NotEnoughRights
+        } else if starts("message is not modified") {
+            ApiErrorKind::MessageNotModified
+        } else if starts("message to edit not found") {
+            ApiErrorKind::MessageToEditNotFound
+        } else if starts("message to delete not found") {
+            ApiErrorKind::MessageToDeleteNotFound
+        } else if starts("message can't be edited") {
+            ApiErrorKind::MessageCantBeEdited
+        } else if starts("message can't be deleted") {
+            ApiErrorKind::MessageCantBeDeleted
+        } else if starts("replied message not found") || starts("reply message not found") {
+            ApiErrorKind::ReplyMessageNotFound
+        } else if starts("message text is empty") {
+            ApiErrorKind::MessageTextEmpty
+        } else if starts("message is too long") {
+            ApiErrorKind::MessageTooLong
+        } else if starts("can't parse entities") {
+            ApiErrorKind::CantParseEntities
+        } else if starts("query is too old") {
+            ApiErrorKind::QueryTooOld
+        } else if starts("wrong file identifier") || starts("wrong remote file identifier") {
+            ApiErrorKind::WrongFileIdentifier
+        } else {
+            ApiErrorKind::Other
+        }
+    }
+}
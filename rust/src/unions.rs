@@ -0,0 +1,176 @@
+//! Wire (de)serialization of the union types.
+//!
+//! Telegram never wraps union members into an external tag, so the derived
+//! representation of the enums in the crate root can't be used directly.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use {InlineQueryResult, InlineQueryResultArticle, InlineQueryResultAudio,
+     InlineQueryResultCachedAudio, InlineQueryResultCachedDocument, InlineQueryResultCachedGif,
+     InlineQueryResultCachedMpeg4Gif, InlineQueryResultCachedPhoto,
+     InlineQueryResultCachedSticker, InlineQueryResultCachedVideo, InlineQueryResultCachedVoice,
+     InlineQueryResultContact, InlineQueryResultDocument, InlineQueryResultGame,
+     InlineQueryResultGif, InlineQueryResultLocation, InlineQueryResultMpeg4Gif,
+     InlineQueryResultPhoto, InlineQueryResultVenue, InlineQueryResultVideo,
+     InlineQueryResultVoice, InputContactMessageContent, InputInvoiceMessageContent,
+     InputLocationMessageContent, InputMessageContent, InputTextMessageContent,
+     InputVenueMessageContent};
+
+/// Cached and non-cached results share the same `type`, so the variant is
+/// picked by the presence of `*_file_id` (cached) or `*_url` (by link).
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CachedOrLink<C, L> {
+    Cached(C),
+    Link(L),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum InlineQueryResultRepr {
+    Audio(CachedOrLink<Box<InlineQueryResultCachedAudio>, Box<InlineQueryResultAudio>>),
+    Document(CachedOrLink<Box<InlineQueryResultCachedDocument>, Box<InlineQueryResultDocument>>),
+    Gif(CachedOrLink<Box<InlineQueryResultCachedGif>, Box<InlineQueryResultGif>>),
+    Mpeg4Gif(CachedOrLink<Box<InlineQueryResultCachedMpeg4Gif>, Box<InlineQueryResultMpeg4Gif>>),
+    Photo(CachedOrLink<Box<InlineQueryResultCachedPhoto>, Box<InlineQueryResultPhoto>>),
+    Video(CachedOrLink<Box<InlineQueryResultCachedVideo>, Box<InlineQueryResultVideo>>),
+    Voice(CachedOrLink<Box<InlineQueryResultCachedVoice>, Box<InlineQueryResultVoice>>),
+    Sticker(Box<InlineQueryResultCachedSticker>),
+    Article(Box<InlineQueryResultArticle>),
+    Contact(Box<InlineQueryResultContact>),
+    Game(Box<InlineQueryResultGame>),
+    Location(Box<InlineQueryResultLocation>),
+    Venue(Box<InlineQueryResultVenue>),
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum InlineQueryResultReprRef<'a> {
+    Audio(&'a InlineQueryResultAudio),
+    Document(&'a InlineQueryResultDocument),
+    Gif(&'a InlineQueryResultGif),
+    Mpeg4Gif(&'a InlineQueryResultMpeg4Gif),
+    Photo(&'a InlineQueryResultPhoto),
+    Video(&'a InlineQueryResultVideo),
+    Voice(&'a InlineQueryResultVoice),
+    Article(&'a InlineQueryResultArticle),
+    Contact(&'a InlineQueryResultContact),
+    Game(&'a InlineQueryResultGame),
+    Location(&'a InlineQueryResultLocation),
+    Venue(&'a InlineQueryResultVenue),
+    #[serde(rename = "audio")]
+    CachedAudio(&'a InlineQueryResultCachedAudio),
+    #[serde(rename = "document")]
+    CachedDocument(&'a InlineQueryResultCachedDocument),
+    #[serde(rename = "gif")]
+    CachedGif(&'a InlineQueryResultCachedGif),
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(&'a InlineQueryResultCachedMpeg4Gif),
+    #[serde(rename = "photo")]
+    CachedPhoto(&'a InlineQueryResultCachedPhoto),
+    #[serde(rename = "sticker")]
+    CachedSticker(&'a InlineQueryResultCachedSticker),
+    #[serde(rename = "video")]
+    CachedVideo(&'a InlineQueryResultCachedVideo),
+    #[serde(rename = "voice")]
+    CachedVoice(&'a InlineQueryResultCachedVoice),
+}
+
+impl Serialize for InlineQueryResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use self::InlineQueryResultReprRef as R;
+
+        let repr = match *self {
+            InlineQueryResult::InlineQueryResultCachedAudio(ref r) => R::CachedAudio(r),
+            InlineQueryResult::InlineQueryResultCachedDocument(ref r) => R::CachedDocument(r),
+            InlineQueryResult::InlineQueryResultCachedGif(ref r) => R::CachedGif(r),
+            InlineQueryResult::InlineQueryResultCachedMpeg4Gif(ref r) => R::CachedMpeg4Gif(r),
+            InlineQueryResult::InlineQueryResultCachedPhoto(ref r) => R::CachedPhoto(r),
+            InlineQueryResult::InlineQueryResultCachedSticker(ref r) => R::CachedSticker(r),
+            InlineQueryResult::InlineQueryResultCachedVideo(ref r) => R::CachedVideo(r),
+            InlineQueryResult::InlineQueryResultCachedVoice(ref r) => R::CachedVoice(r),
+            InlineQueryResult::InlineQueryResultArticle(ref r) => R::Article(r),
+            InlineQueryResult::InlineQueryResultAudio(ref r) => R::Audio(r),
+            InlineQueryResult::InlineQueryResultContact(ref r) => R::Contact(r),
+            InlineQueryResult::InlineQueryResultGame(ref r) => R::Game(r),
+            InlineQueryResult::InlineQueryResultDocument(ref r) => R::Document(r),
+            InlineQueryResult::InlineQueryResultGif(ref r) => R::Gif(r),
+            InlineQueryResult::InlineQueryResultLocation(ref r) => R::Location(r),
+            InlineQueryResult::InlineQueryResultMpeg4Gif(ref r) => R::Mpeg4Gif(r),
+            InlineQueryResult::InlineQueryResultPhoto(ref r) => R::Photo(r),
+            InlineQueryResult::InlineQueryResultVenue(ref r) => R::Venue(r),
+            InlineQueryResult::InlineQueryResultVideo(ref r) => R::Video(r),
+            InlineQueryResult::InlineQueryResultVoice(ref r) => R::Voice(r),
+        };
+
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for InlineQueryResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use self::CachedOrLink::{Cached, Link};
+        use self::InlineQueryResultRepr as R;
+
+        Ok(match R::deserialize(deserializer)? {
+            R::Audio(Cached(r)) => InlineQueryResult::InlineQueryResultCachedAudio(r),
+            R::Audio(Link(r)) => InlineQueryResult::InlineQueryResultAudio(r),
+            R::Document(Cached(r)) => InlineQueryResult::InlineQueryResultCachedDocument(r),
+            R::Document(Link(r)) => InlineQueryResult::InlineQueryResultDocument(r),
+            R::Gif(Cached(r)) => InlineQueryResult::InlineQueryResultCachedGif(r),
+            R::Gif(Link(r)) => InlineQueryResult::InlineQueryResultGif(r),
+            R::Mpeg4Gif(Cached(r)) => InlineQueryResult::InlineQueryResultCachedMpeg4Gif(r),
+            R::Mpeg4Gif(Link(r)) => InlineQueryResult::InlineQueryResultMpeg4Gif(r),
+            R::Photo(Cached(r)) => InlineQueryResult::InlineQueryResultCachedPhoto(r),
+            R::Photo(Link(r)) => InlineQueryResult::InlineQueryResultPhoto(r),
+            R::Video(Cached(r)) => InlineQueryResult::InlineQueryResultCachedVideo(r),
+            R::Video(Link(r)) => InlineQueryResult::InlineQueryResultVideo(r),
+            R::Voice(Cached(r)) => InlineQueryResult::InlineQueryResultCachedVoice(r),
+            R::Voice(Link(r)) => InlineQueryResult::InlineQueryResultVoice(r),
+            R::Sticker(r) => InlineQueryResult::InlineQueryResultCachedSticker(r),
+            R::Article(r) => InlineQueryResult::InlineQueryResultArticle(r),
+            R::Contact(r) => InlineQueryResult::InlineQueryResultContact(r),
+            R::Game(r) => InlineQueryResult::InlineQueryResultGame(r),
+            R::Location(r) => InlineQueryResult::InlineQueryResultLocation(r),
+            R::Venue(r) => InlineQueryResult::InlineQueryResultVenue(r),
+        })
+    }
+}
+
+/// Variants are tried in order, so the shapes with more required fields go
+/// first: a venue is also a valid location.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InputMessageContentRepr {
+    Text(Box<InputTextMessageContent>),
+    Invoice(Box<InputInvoiceMessageContent>),
+    Venue(Box<InputVenueMessageContent>),
+    Contact(Box<InputContactMessageContent>),
+    Location(Box<InputLocationMessageContent>),
+}
+
+impl Serialize for InputMessageContent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            InputMessageContent::InputTextMessageContent(ref c) => c.serialize(serializer),
+            InputMessageContent::InputLocationMessageContent(ref c) => c.serialize(serializer),
+            InputMessageContent::InputVenueMessageContent(ref c) => c.serialize(serializer),
+            InputMessageContent::InputContactMessageContent(ref c) => c.serialize(serializer),
+            InputMessageContent::InputInvoiceMessageContent(ref c) => c.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InputMessageContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use self::InputMessageContentRepr as R;
+
+        Ok(match R::deserialize(deserializer)? {
+            R::Text(c) => InputMessageContent::InputTextMessageContent(c),
+            R::Invoice(c) => InputMessageContent::InputInvoiceMessageContent(c),
+            R::Venue(c) => InputMessageContent::InputVenueMessageContent(c),
+            R::Contact(c) => InputMessageContent::InputContactMessageContent(c),
+            R::Location(c) => InputMessageContent::InputLocationMessageContent(c),
+        })
+    }
+}
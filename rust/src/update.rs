@@ -0,0 +1,171 @@
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+
+use {
+    CallbackQuery, ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message, Poll, PollAnswer,
+    PreCheckoutQuery, ShippingQuery, Update,
+};
+
+/// The only payload of an incoming update.
+///
+/// Deserializes straight from an Update object (update_id is skipped) and
+/// serializes back to an object with one field at most, e.g.
+/// `{"message": {...}}`.
+/// See https://core.telegram.org/bots/api#update
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateKind {
+    /// New incoming message of any kind — text, photo, sticker, etc.
+    Message(Box<Message>),
+    /// New version of a message that is known to the bot and was edited
+    EditedMessage(Box<Message>),
+    /// New incoming channel post of any kind — text, photo, sticker, etc.
+    ChannelPost(Box<Message>),
+    /// New version of a channel post that is known to the bot and was edited
+    EditedChannelPost(Box<Message>),
+    /// New incoming inline query
+    InlineQuery(Box<InlineQuery>),
+    /// The result of an inline query that was chosen by a user and sent to
+    /// their chat partner
+    ChosenInlineResult(Box<ChosenInlineResult>),
+    /// New incoming callback query
+    CallbackQuery(Box<CallbackQuery>),
+    /// New incoming shipping query. Only for invoices with flexible price
+    ShippingQuery(Box<ShippingQuery>),
+    /// New incoming pre-checkout query. Contains full information about checkout
+    PreCheckoutQuery(Box<PreCheckoutQuery>),
+    /// New poll state. Bots receive only updates about stopped polls and
+    /// polls, which are sent by the bot
+    Poll(Box<Poll>),
+    /// A user changed their answer in a non-anonymous poll. Bots receive new
+    /// votes only in polls that were sent by the bot itself
+    PollAnswer(Box<PollAnswer>),
+    /// The bot's chat member status was updated in a chat
+    MyChatMember(Box<ChatMemberUpdated>),
+    /// A chat member's status was updated in a chat
+    ChatMember(Box<ChatMemberUpdated>),
+    /// An update type this crate doesn't model yet, or an update without a
+    /// payload. Holds the name of the field when it's known: an Update keeps
+    /// it only with the `extra-fields` feature. The payload itself is lost,
+    /// so this serializes back to e.g. `{"message_reaction": null}`.
+    #[serde(untagged, serialize_with = "serialize_unknown")]
+    Unknown(Option<String>),
+}
+
+impl Update {
+    /// Takes the only payload out of the update
+    pub fn into_kind(self) -> UpdateKind {
+        UpdateKind::from(self)
+    }
+}
+
+impl From<Update> for UpdateKind {
+    fn from(mut update: Update) -> UpdateKind {
+        let u = &mut update;
+
+        u.message
+            .take()
+            .map(UpdateKind::Message)
+            .or_else(|| u.edited_message.take().map(UpdateKind::EditedMessage))
+            .or_else(|| u.channel_post.take().map(UpdateKind::ChannelPost))
+            .or_else(|| {
+                u.edited_channel_post
+                    .take()
+                    .map(UpdateKind::EditedChannelPost)
+            })
+            .or_else(|| u.inline_query.take().map(UpdateKind::InlineQuery))
+            .or_else(|| {
+                u.chosen_inline_result
+                    .take()
+                    .map(UpdateKind::ChosenInlineResult)
+            })
+            .or_else(|| u.callback_query.take().map(UpdateKind::CallbackQuery))
+            .or_else(|| u.shipping_query.take().map(UpdateKind::ShippingQuery))
+            .or_else(|| {
+                u.pre_checkout_query
+                    .take()
+                    .map(UpdateKind::PreCheckoutQuery)
+            })
+            .or_else(|| u.poll.take().map(UpdateKind::Poll))
+            .or_else(|| u.poll_answer.take().map(UpdateKind::PollAnswer))
+            .or_else(|| u.my_chat_member.take().map(UpdateKind::MyChatMember))
+            .or_else(|| u.chat_member.take().map(UpdateKind::ChatMember))
+            .unwrap_or_else(|| UpdateKind::Unknown(unknown_field(u)))
+    }
+}
+
+#[cfg(feature = "extra-fields")]
+fn unknown_field(update: &Update) -> Option<String> {
+    update.extra.keys().next().cloned()
+}
+
+#[cfg(not(feature = "extra-fields"))]
+fn unknown_field(_update: &Update) -> Option<String> {
+    None
+}
+
+fn serialize_unknown<S: Serializer>(
+    field: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(field.iter().count()))?;
+    if let Some(ref field) = *field {
+        map.serialize_entry(field, &())?;
+    }
+    map.end()
+}
+
+struct UpdateKindVisitor;
+
+impl<'de> Visitor<'de> for UpdateKindVisitor {
+    type Value = UpdateKind;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an Update object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<UpdateKind, A::Error> {
+        let mut kind = None;
+        let mut unknown = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            if kind.is_some() {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+
+            kind = match key.as_str() {
+                "message" => Some(UpdateKind::Message(map.next_value()?)),
+                "edited_message" => Some(UpdateKind::EditedMessage(map.next_value()?)),
+                "channel_post" => Some(UpdateKind::ChannelPost(map.next_value()?)),
+                "edited_channel_post" => Some(UpdateKind::EditedChannelPost(map.next_value()?)),
+                "inline_query" => Some(UpdateKind::InlineQuery(map.next_value()?)),
+                "chosen_inline_result" => Some(UpdateKind::ChosenInlineResult(map.next_value()?)),
+                "callback_query" => Some(UpdateKind::CallbackQuery(map.next_value()?)),
+                "shipping_query" => Some(UpdateKind::ShippingQuery(map.next_value()?)),
+                "pre_checkout_query" => Some(UpdateKind::PreCheckoutQuery(map.next_value()?)),
+                "poll" => Some(UpdateKind::Poll(map.next_value()?)),
+                "poll_answer" => Some(UpdateKind::PollAnswer(map.next_value()?)),
+                "my_chat_member" => Some(UpdateKind::MyChatMember(map.next_value()?)),
+                "chat_member" => Some(UpdateKind::ChatMember(map.next_value()?)),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                    if key != "update_id" && unknown.is_none() {
+                        unknown = Some(key);
+                    }
+                    None
+                }
+            };
+        }
+
+        Ok(kind.unwrap_or(UpdateKind::Unknown(unknown)))
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(UpdateKindVisitor)
+    }
+}
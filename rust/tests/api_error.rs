@@ -0,0 +1,195 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use std::time::Duration;
+
+use telegram_typings::{ApiErrorKind, ApiResponse, ChatId, Message, ResponseError};
+
+/// Descriptions as sent by the Bot API
+const CORPUS: &[(i64, &str, ApiErrorKind)] = &[
+    (401, "Unauthorized", ApiErrorKind::Unauthorized),
+    (
+        409,
+        "Conflict: terminated by other getUpdates request; make sure that only one bot instance is running",
+        ApiErrorKind::TerminatedByOtherGetUpdates,
+    ),
+    (
+        409,
+        "Conflict: can't use getUpdates method while webhook is active; use deleteWebhook to delete the webhook first",
+        ApiErrorKind::WebhookActive,
+    ),
+    (
+        429,
+        "Too Many Requests: retry after 35",
+        ApiErrorKind::RetryAfter(Duration::from_secs(35)),
+    ),
+    (400, "Bad Request: chat not found", ApiErrorKind::ChatNotFound),
+    (400, "Bad Request: user not found", ApiErrorKind::UserNotFound),
+    (
+        403,
+        "Forbidden: bot was blocked by the user",
+        ApiErrorKind::BotBlocked,
+    ),
+    (
+        403,
+        "Forbidden: user is deactivated",
+        ApiErrorKind::UserDeactivated,
+    ),
+    (
+        403,
+        "Forbidden: bot was kicked from the group chat",
+        ApiErrorKind::BotKicked,
+    ),
+    (
+        403,
+        "Forbidden: bot was kicked from the supergroup chat",
+        ApiErrorKind::BotKicked,
+    ),
+    (
+        403,
+        "Forbidden: bot is not a member of the channel chat",
+        ApiErrorKind::BotNotMember,
+    ),
+    (
+        403,
+        "Forbidden: bot can't initiate conversation with a user",
+        ApiErrorKind::CantInitiateConversation,
+    ),
+    (
+        403,
+        "Forbidden: bot can't send messages to bots",
+        ApiErrorKind::CantMessageBots,
+    ),
+    (
+        400,
+        "Bad Request: not enough rights to send text messages to the chat",
+        ApiErrorKind::NotEnoughRights,
+    ),
+    (
+        400,
+        "Bad Request: have no rights to send a message",
+        ApiErrorKind::NotEnoughRights,
+    ),
+    (
+        400,
+        "Bad Request: message is not modified: specified new message content and reply markup are exactly the same as a current content and reply markup of the message",
+        ApiErrorKind::MessageNotModified,
+    ),
+    (
+        400,
+        "Bad Request: message to edit not found",
+        ApiErrorKind::MessageToEditNotFound,
+    ),
+    (
+        400,
+        "Bad Request: message to delete not found",
+        ApiErrorKind::MessageToDeleteNotFound,
+    ),
+    (
+        400,
+        "Bad Request: message can't be edited",
+        ApiErrorKind::MessageCantBeEdited,
+    ),
+    (
+        400,
+        "Bad Request: message can't be deleted for everyone",
+        ApiErrorKind::MessageCantBeDeleted,
+    ),
+    (
+        400,
+        "Bad Request: replied message not found",
+        ApiErrorKind::ReplyMessageNotFound,
+    ),
+    (
+        400,
+        "Bad Request: reply message not found",
+        ApiErrorKind::ReplyMessageNotFound,
+    ),
+    (
+        400,
+        "Bad Request: message text is empty",
+        ApiErrorKind::MessageTextEmpty,
+    ),
+    (
+        400,
+        "Bad Request: message is too long",
+        ApiErrorKind::MessageTooLong,
+    ),
+    (
+        400,
+        "Bad Request: can't parse entities: Can't find end of the entity starting at byte offset 12",
+        ApiErrorKind::CantParseEntities,
+    ),
+    (
+        400,
+        "Bad Request: query is too old and response timeout expired or query ID is invalid",
+        ApiErrorKind::QueryTooOld,
+    ),
+    (
+        400,
+        "Bad Request: wrong file identifier/HTTP URL specified",
+        ApiErrorKind::WrongFileIdentifier,
+    ),
+    (
+        400,
+        "Bad Request: wrong remote file identifier specified: Wrong character in the string",
+        ApiErrorKind::WrongFileIdentifier,
+    ),
+    (
+        400,
+        "Bad Request: group chat was upgraded to a supergroup chat",
+        ApiErrorKind::Other,
+    ),
+    (400, "Bad Request: PEER_ID_INVALID", ApiErrorKind::Other),
+    (
+        502,
+        "Bad Gateway",
+        ApiErrorKind::Other,
+    ),
+];
+
+#[test]
+fn corpus() {
+    for &(error_code, description, expected) in CORPUS {
+        assert_eq!(
+            ApiErrorKind::parse(error_code, description),
+            expected,
+            "{} {}",
+            error_code,
+            description
+        );
+    }
+}
+
+fn error(json: &str) -> ApiErrorKind {
+    let response: ApiResponse<Message> = serde_json::from_str(json).unwrap();
+
+    match response.into_result() {
+        Err(ResponseError::Api(error)) => error.kind(),
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[test]
+fn parameters_go_first() {
+    let kind = error(
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1001234567890}}"#,
+    );
+
+    assert_eq!(kind, ApiErrorKind::MigrateToChat(ChatId(-1001234567890)));
+
+    let kind = error(
+        r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 7","parameters":{"retry_after":7}}"#,
+    );
+
+    assert_eq!(kind, ApiErrorKind::RetryAfter(Duration::from_secs(7)));
+}
+
+#[test]
+fn description_without_parameters() {
+    let kind = error(
+        r#"{"ok":false,"error_code":403,"description":"Forbidden: bot was blocked by the user"}"#,
+    );
+
+    assert_eq!(kind, ApiErrorKind::BotBlocked);
+}
@@ -0,0 +1,60 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{ApiResponse, ResponseError};
+
+fn parse(json: &str) -> ApiResponse<bool> {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn successful_response() {
+    let response = parse(r#"{"ok":true,"result":true}"#);
+
+    assert!(response.into_result().unwrap());
+}
+
+#[test]
+fn unsuccessful_response() {
+    let response =
+        parse(r#"{"ok":false,"error_code":400,"description":"Bad Request: chat not found"}"#);
+
+    match response.into_result().unwrap_err() {
+        ResponseError::Api(error) => {
+            assert_eq!(error.error_code, 400);
+            assert_eq!(
+                error.to_string(),
+                "Bad Request: chat not found (error code 400)"
+            );
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+}
+
+#[test]
+fn unsuccessful_response_with_result() {
+    let response = parse(r#"{"ok":false,"result":true,"error_code":500,"description":"Internal"}"#);
+    let error = response.into_result().unwrap_err();
+
+    assert_eq!(error.api_error().unwrap().error_code, 500);
+}
+
+#[test]
+fn missing_result() {
+    let error = parse(r#"{"ok":true}"#).into_result().unwrap_err();
+
+    match error {
+        ResponseError::MissingResult => {}
+        ref other => panic!("expected a missing result, got {:?}", other),
+    }
+    assert!(error.api_error().is_none());
+    assert_eq!(error.to_string(), "successful response has no result");
+}
+
+#[test]
+fn round_trip() {
+    let json = r#"{"ok":false,"description":"Too Many Requests: retry after 7","error_code":429,"parameters":{"retry_after":7}}"#;
+    let response = parse(json);
+
+    assert_eq!(serde_json::to_string(&response).unwrap(), json);
+}
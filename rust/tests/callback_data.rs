@@ -0,0 +1,121 @@
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{
+    from_callback_data, from_callback_data_with, to_callback_data, to_callback_data_with,
+    CallbackDataError, CallbackQuery, MemoryStore, CALLBACK_DATA_LIMIT,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Action {
+    Refresh,
+    Like(i64),
+    Page { list: String, page: u32 },
+    Filter(Option<String>, bool),
+    Pick(Vec<u8>),
+}
+
+fn round_trip(action: Action, expected: &str) {
+    let data = to_callback_data(&action).unwrap();
+
+    assert_eq!(data, expected);
+    assert_eq!(from_callback_data::<Action>(&data).unwrap(), action);
+}
+
+#[test]
+fn compact_form() {
+    round_trip(Action::Refresh, "refresh");
+    round_trip(Action::Like(-42), "like:-42");
+    round_trip(
+        Action::Page {
+            list: "cats".to_owned(),
+            page: 3,
+        },
+        "page:cats:3",
+    );
+    round_trip(Action::Filter(None, true), "filter:~:1");
+    round_trip(Action::Pick(vec![1, 2]), "pick:2:1:2");
+}
+
+#[test]
+fn strings_are_escaped() {
+    round_trip(
+        Action::Filter(Some("a:b\\c".to_owned()), false),
+        "filter:a\\:b\\\\c:0",
+    );
+    round_trip(Action::Filter(Some("~".to_owned()), false), "filter:\\~:0");
+    round_trip(Action::Filter(Some(String::new()), false), "filter::0");
+}
+
+#[test]
+fn limit() {
+    let long = Action::Page {
+        list: "x".repeat(CALLBACK_DATA_LIMIT),
+        page: 1,
+    };
+
+    let error = to_callback_data(&long).unwrap_err();
+    assert_eq!(error, CallbackDataError::TooLong { length: 71 });
+    assert_eq!(
+        error.to_string(),
+        "callback data is 71 bytes long, at most 64 are allowed"
+    );
+
+    assert_eq!(to_callback_data(&()), Err(CallbackDataError::Empty));
+}
+
+#[test]
+fn invalid_data() {
+    assert!(from_callback_data::<Action>("like").is_err());
+    assert!(from_callback_data::<Action>("like:x").is_err());
+    assert!(from_callback_data::<Action>("like:1:2").is_err());
+    assert!(from_callback_data::<Action>("dislike:1").is_err());
+}
+
+#[test]
+fn overflow_store() {
+    let mut store = MemoryStore::new();
+    let long = Action::Page {
+        list: "x".repeat(100),
+        page: 7,
+    };
+
+    let data = to_callback_data_with(&long, &mut store).unwrap();
+    assert!(data.starts_with('#'));
+    assert!(data.len() <= CALLBACK_DATA_LIMIT);
+    assert_eq!(to_callback_data_with(&long, &mut store).unwrap(), data);
+    assert_eq!(
+        from_callback_data_with::<Action, _>(&data, &store).unwrap(),
+        long
+    );
+
+    let short = to_callback_data_with(&Action::Like(1), &mut store).unwrap();
+    assert_eq!(short, "like:1");
+    assert_eq!(
+        from_callback_data_with::<Action, _>(&short, &store).unwrap(),
+        Action::Like(1)
+    );
+
+    assert_eq!(
+        from_callback_data_with::<Action, _>("#missing", &store),
+        Err(CallbackDataError::UnknownKey("missing".to_owned()))
+    );
+}
+
+#[test]
+fn callback_query() {
+    let query: CallbackQuery = serde_json::from_str(
+        r#"{
+            "id": "4382bfdwdsb323b2d9",
+            "from": {"id": 1001, "is_bot": false, "first_name": "Ann"},
+            "chat_instance": "-1234",
+            "data": "like:42"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(query.data_as::<Action>().unwrap(), Action::Like(42));
+}
@@ -0,0 +1,115 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::ChatMember;
+
+const USER: &str = r#"{"id":1001,"is_bot":false,"first_name":"Ann"}"#;
+
+fn round_trip(status: &str, fields: &str) -> ChatMember {
+    let json = format!(r#"{{"status":"{}","user":{}{}}}"#, status, USER, fields);
+    let member: ChatMember = serde_json::from_str(&json).unwrap();
+    let value = serde_json::to_value(&member).unwrap();
+
+    assert_eq!(value, serde_json::from_str::<Value>(&json).unwrap());
+    assert_eq!(member.status(), status);
+    assert_eq!(member.user().first_name, "Ann");
+
+    member
+}
+
+#[test]
+fn owner() {
+    let member = round_trip("creator", r#","is_anonymous":false,"custom_title":"Boss""#);
+
+    match member {
+        ChatMember::ChatMemberOwner(ref m) => assert_eq!(m.custom_title.as_deref(), Some("Boss")),
+        ref other => panic!("expected an owner, got {:?}", other),
+    }
+    assert!(member.is_admin());
+    assert!(member.is_member());
+    assert_eq!(member.until_date(), None);
+}
+
+#[test]
+fn administrator() {
+    let member = round_trip(
+        "administrator",
+        r#","can_be_edited":true,"is_anonymous":false,"can_manage_chat":true,"can_delete_messages":true,"can_manage_voice_chats":false,"can_restrict_members":true,"can_promote_members":false,"can_change_info":true,"can_invite_users":true"#,
+    );
+
+    match member {
+        ChatMember::ChatMemberAdministrator(ref m) => assert!(m.can_restrict_members),
+        ref other => panic!("expected an administrator, got {:?}", other),
+    }
+    assert!(member.is_admin());
+    assert!(member.is_member());
+    assert_eq!(member.until_date(), None);
+}
+
+#[test]
+fn member() {
+    let member = round_trip("member", "");
+
+    match member {
+        ChatMember::ChatMemberMember(_) => {}
+        ref other => panic!("expected a member, got {:?}", other),
+    }
+    assert!(!member.is_admin());
+    assert!(member.is_member());
+    assert_eq!(member.until_date(), None);
+}
+
+#[test]
+fn restricted() {
+    let fields = |is_member: bool| {
+        format!(
+            r#","is_member":{},"can_change_info":false,"can_invite_users":false,"can_pin_messages":false,"can_send_messages":true,"can_send_media_messages":false,"can_send_polls":false,"can_send_other_messages":false,"can_add_web_page_previews":false,"until_date":1700000000"#,
+            is_member
+        )
+    };
+
+    let member = round_trip("restricted", &fields(true));
+    match member {
+        ChatMember::ChatMemberRestricted(ref m) => assert!(m.can_send_messages),
+        ref other => panic!("expected a restricted member, got {:?}", other),
+    }
+    assert!(!member.is_admin());
+    assert!(member.is_member());
+    assert_eq!(member.until_date(), Some(1700000000));
+
+    assert!(!round_trip("restricted", &fields(false)).is_member());
+}
+
+#[test]
+fn left() {
+    let member = round_trip("left", "");
+
+    match member {
+        ChatMember::ChatMemberLeft(_) => {}
+        ref other => panic!("expected a member that left, got {:?}", other),
+    }
+    assert!(!member.is_admin());
+    assert!(!member.is_member());
+    assert_eq!(member.until_date(), None);
+}
+
+#[test]
+fn banned() {
+    let member = round_trip("kicked", r#","until_date":0"#);
+
+    match member {
+        ChatMember::ChatMemberBanned(_) => {}
+        ref other => panic!("expected a banned member, got {:?}", other),
+    }
+    assert!(!member.is_admin());
+    assert!(!member.is_member());
+    assert_eq!(member.until_date(), Some(0));
+}
+
+#[test]
+fn unknown_status() {
+    let json = format!(r#"{{"status":"ghost","user":{}}}"#, USER);
+
+    assert!(serde_json::from_str::<ChatMember>(&json).is_err());
+}
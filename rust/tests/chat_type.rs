@@ -0,0 +1,68 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{Chat, ChatType};
+
+fn chat(type_tl: &str) -> Chat {
+    serde_json::from_value(serde_json::json!({
+        "id": -1001234567890i64,
+        "type": type_tl,
+        "title": "Chat",
+    }))
+    .unwrap()
+}
+
+#[test]
+fn known_types() {
+    let types = [
+        ("private", ChatType::Private),
+        ("group", ChatType::Group),
+        ("supergroup", ChatType::Supergroup),
+        ("channel", ChatType::Channel),
+    ];
+
+    for &(name, ref expected) in &types {
+        let json = format!("\"{}\"", name);
+        let chat_type: ChatType = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(&chat_type, expected);
+        assert_eq!(chat_type.as_str(), name);
+        assert_eq!(chat_type.to_string(), name);
+        assert_eq!(serde_json::to_string(&chat_type).unwrap(), json);
+        assert_eq!(&chat(name).type_tl, expected);
+    }
+}
+
+#[test]
+fn unknown_type() {
+    let chat_type: ChatType = serde_json::from_str("\"forum\"").unwrap();
+
+    assert_eq!(chat_type, ChatType::Unknown("forum".to_owned()));
+    assert_eq!(serde_json::to_string(&chat_type).unwrap(), "\"forum\"");
+
+    let chat = chat("forum");
+    assert_eq!(serde_json::to_value(&chat).unwrap()["type"], "forum");
+    assert!(!chat.is_private() && !chat.is_group_like() && !chat.is_channel());
+}
+
+#[test]
+fn predicates() {
+    let private = chat("private");
+    assert!(private.is_private());
+    assert!(!private.is_group_like());
+    assert!(!private.is_channel());
+
+    for name in &["group", "supergroup"] {
+        let group = chat(name);
+        assert!(!group.is_private());
+        assert!(group.is_group_like());
+        assert!(!group.is_channel());
+    }
+    assert!(chat("group").is_group() && !chat("group").is_supergroup());
+    assert!(chat("supergroup").is_supergroup() && !chat("supergroup").is_group());
+
+    let channel = chat("channel");
+    assert!(!channel.is_private());
+    assert!(!channel.is_group_like());
+    assert!(channel.is_channel());
+}
@@ -0,0 +1,104 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{Message, MessageEntity};
+
+fn entity(offset: i64, length: i64) -> MessageEntity {
+    serde_json::from_value(serde_json::json!({
+        "type": "bold",
+        "offset": offset,
+        "length": length,
+    }))
+    .unwrap()
+}
+
+fn message(text: &str, entities: &[(i64, i64)]) -> Message {
+    let entities: Vec<_> = entities
+        .iter()
+        .map(|&(offset, length)| serde_json::json!({"type": "bold", "offset": offset, "length": length}))
+        .collect();
+
+    serde_json::from_value(serde_json::json!({
+        "message_id": 1,
+        "chat": {"id": 1, "type": "private"},
+        "date": 0,
+        "text": text,
+        "entities": entities,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn ascii() {
+    assert_eq!(entity(6, 5).text_in("Hello world"), Some("world"));
+    assert_eq!(entity(0, 0).text_in("Hello"), Some(""));
+    assert_eq!(entity(5, 0).text_in("Hello"), Some(""));
+    assert_eq!(entity(3, 5).text_in("Hello"), None);
+    assert_eq!(entity(-1, 2).text_in("Hello"), None);
+}
+
+#[test]
+fn astral_plane() {
+    // 😀 and 𝕏 are two UTF-16 code units and four bytes each
+    let text = "😀 hi 𝕏 there";
+
+    assert_eq!(entity(0, 2).text_in(text), Some("😀"));
+    assert_eq!(entity(3, 2).text_in(text), Some("hi"));
+    assert_eq!(entity(6, 2).text_in(text), Some("𝕏"));
+    assert_eq!(entity(9, 5).text_in(text), Some("there"));
+    assert_eq!(entity(6, 2).byte_range(text), Some(8..12));
+
+    // Half of a surrogate pair
+    assert_eq!(entity(0, 1).text_in(text), None);
+    assert_eq!(entity(1, 2).text_in(text), None);
+    assert_eq!(entity(14, 1).text_in(text), None);
+}
+
+#[test]
+fn combining_sequences() {
+    // "e" with a combining acute accent, a family joined with ZWJ and a flag
+    let text = "Cafe\u{301} 👨\u{200d}👩\u{200d}👧 🇺🇦!";
+
+    assert_eq!(entity(0, 5).text_in(text), Some("Cafe\u{301}"));
+    assert_eq!(entity(3, 2).text_in(text), Some("e\u{301}"));
+    assert_eq!(entity(6, 8).text_in(text), Some("👨\u{200d}👩\u{200d}👧"));
+    assert_eq!(entity(15, 4).text_in(text), Some("🇺🇦"));
+    assert_eq!(entity(19, 1).text_in(text), Some("!"));
+
+    // Splitting a sequence between its characters is allowed
+    assert_eq!(entity(4, 1).text_in(text), Some("\u{301}"));
+}
+
+#[test]
+fn message_entities() {
+    let message = message("👋 Hello, 𝕎orld!", &[(0, 2), (3, 5), (10, 6), (40, 2)]);
+    let texts: Vec<_> = message
+        .entity_texts()
+        .map(|(entity, text)| (entity.offset, text))
+        .collect();
+
+    assert_eq!(texts, [(0, "👋"), (3, "Hello"), (10, "𝕎orld")]);
+
+    let entities = message.entities.as_ref().unwrap();
+    assert_eq!(message.entity_text(&entities[1]), Some("Hello"));
+    assert_eq!(message.entity_text(&entities[3]), None);
+}
+
+#[test]
+fn caption_entities() {
+    let message: Message = serde_json::from_value(serde_json::json!({
+        "message_id": 1,
+        "chat": {"id": 1, "type": "private"},
+        "date": 0,
+        "photo": [],
+        "caption": "🐈 Cat",
+        "caption_entities": [{"type": "italic", "offset": 3, "length": 3}],
+    }))
+    .unwrap();
+
+    let texts: Vec<_> = message.entity_texts().map(|(_, text)| text).collect();
+    assert_eq!(texts, ["Cat"]);
+
+    let entity = &message.caption_entities.as_ref().unwrap()[0];
+    assert_eq!(message.entity_text(entity), Some("Cat"));
+}
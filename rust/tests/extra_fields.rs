@@ -0,0 +1,27 @@
+#![cfg(feature = "extra-fields")]
+
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::Message;
+
+#[test]
+fn unknown_fields_survive_round_trip() {
+    let json = r#"{
+        "message_id": 42,
+        "date": 1600000000,
+        "chat": {"id": 1, "type": "private", "first_name": "Ivan", "has_private_forwards": true},
+        "giveaway": {"winner_count": 3, "prize_description": "Stickers"},
+        "text": "hello"
+    }"#;
+
+    let message: Message = serde_json::from_str(json).unwrap();
+
+    assert_eq!(message.extra["giveaway"]["winner_count"], 3);
+    assert_eq!(message.chat.extra["has_private_forwards"], true);
+    assert!(!message.extra.contains_key("text"));
+
+    let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+
+    assert_eq!(serde_json::to_value(&message).unwrap(), expected);
+}
@@ -0,0 +1,53 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{
+    ApiResponse, ChatId, FileId, Message, MessageId, MessageIdentifier, UserId,
+};
+
+const MESSAGE: &str = r#"{
+    "message_id": 7,
+    "from": {"id": 1001, "is_bot": false, "first_name": "Ann"},
+    "chat": {"id": 1001, "type": "private", "first_name": "Ann"},
+    "date": 1600000000,
+    "photo": [{"file_id": "AgAD", "file_unique_id": "AQAD", "width": 90, "height": 90}]
+}"#;
+
+#[test]
+fn fields_are_typed() {
+    let message: Message = serde_json::from_str(MESSAGE).unwrap();
+
+    assert_eq!(message.message_id, MessageIdentifier(7));
+    assert_eq!(message.from.as_ref().unwrap().id, UserId(1001));
+    assert_eq!(message.chat.id, ChatId::from(UserId(1001)));
+    assert_eq!(
+        message.photo.as_ref().unwrap()[0].file_id,
+        FileId::from("AgAD")
+    );
+
+    let value = serde_json::to_value(&message).unwrap();
+
+    assert_eq!(value["message_id"], 7);
+    assert_eq!(value["chat"]["id"], 1001);
+    assert_eq!(value["photo"][0]["file_id"], "AgAD");
+}
+
+#[test]
+fn message_id_object() {
+    let json = r#"{"ok":true,"result":{"message_id":42}}"#;
+    let response: ApiResponse<MessageId> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        response.result.as_ref().unwrap().message_id,
+        MessageIdentifier(42)
+    );
+    assert_eq!(serde_json::to_string(&response).unwrap(), json);
+    assert!(serde_json::from_str::<MessageId>("42").is_err());
+}
+
+#[test]
+fn display() {
+    assert_eq!(ChatId(-1001234567890).to_string(), "-1001234567890");
+    assert_eq!(FileId::from("AgAD").to_string(), "AgAD");
+    assert_eq!(i64::from(MessageIdentifier(3)), 3);
+}
@@ -0,0 +1,91 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::{InlineKeyboardButton, InlineKeyboardButtonAction, Message};
+
+fn round_trip(json: &str) -> InlineKeyboardButton {
+    let button: InlineKeyboardButton = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&button).unwrap(),
+        serde_json::from_str::<Value>(json).unwrap()
+    );
+
+    button
+}
+
+#[test]
+fn same_wire_format() {
+    let button = round_trip(r#"{"text":"Open","url":"https://t.me"}"#);
+    match button.action {
+        InlineKeyboardButtonAction::Url(ref url) => assert_eq!(url, "https://t.me"),
+        ref other => panic!("expected a URL, got {:?}", other),
+    }
+
+    round_trip(r#"{"text":"Like","callback_data":"like:42"}"#);
+    round_trip(r#"{"text":"Share","switch_inline_query":""}"#);
+    round_trip(r#"{"text":"Search","switch_inline_query_current_chat":"cats"}"#);
+    round_trip(r#"{"text":"Log in","login_url":{"url":"https://example.com/login"}}"#);
+    round_trip(r#"{"text":"Play","callback_game":{}}"#);
+    round_trip(r#"{"text":"Pay","pay":true}"#);
+}
+
+#[test]
+fn constructors() {
+    assert_eq!(
+        serde_json::to_string(&InlineKeyboardButton::callback("Like", "like:42")).unwrap(),
+        r#"{"text":"Like","callback_data":"like:42"}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&InlineKeyboardButton::pay("Pay 5 €")).unwrap(),
+        r#"{"text":"Pay 5 €","pay":true}"#
+    );
+}
+
+#[test]
+fn exactly_one_action() {
+    let parse = |json: &str| serde_json::from_str::<InlineKeyboardButton>(json);
+
+    let error = parse(r#"{"text":"?","url":"https://t.me","callback_data":"x"}"#).unwrap_err();
+    assert!(error.to_string().contains("both `url` and `callback_data`"));
+
+    assert!(parse(r#"{"text":"?"}"#).is_err());
+    assert!(parse(r#"{"text":"?","pay":false}"#).is_err());
+    assert!(parse(r#"{"text":"?","pay":false,"callback_data":"x"}"#).is_ok());
+}
+
+#[test]
+fn unknown_action() {
+    let button = round_trip(r#"{"text":"Open","web_app":{"url":"https://x"}}"#);
+    match button.action {
+        InlineKeyboardButtonAction::Unknown {
+            ref field,
+            ref value,
+        } => {
+            assert_eq!(field, "web_app");
+            assert_eq!(value["url"], "https://x");
+        }
+        ref other => panic!("expected an unknown action, got {:?}", other),
+    }
+
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 1,
+            "chat": {"id": 1001, "type": "private"},
+            "date": 0,
+            "reply_markup": {"inline_keyboard": [[{"text": "Open", "web_app": {"url": "https://x"}}]]}
+        }"#,
+    )
+    .unwrap();
+    assert!(message.reply_markup.is_some());
+
+    let button = serde_json::from_str::<InlineKeyboardButton>(
+        r#"{"text":"Open","url":"https://t.me","web_app":{"url":"https://x"}}"#,
+    )
+    .unwrap();
+    match button.action {
+        InlineKeyboardButtonAction::Url(_) => (),
+        ref other => panic!("expected a URL, got {:?}", other),
+    }
+}
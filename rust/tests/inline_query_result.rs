@@ -0,0 +1,81 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::InlineQueryResult;
+
+fn round_trip(json: &str) -> InlineQueryResult {
+    let result: InlineQueryResult = serde_json::from_str(json).unwrap();
+    let value = serde_json::to_value(&result).unwrap();
+
+    assert_eq!(
+        value,
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn cached_photo() {
+    let json = r#"{"type":"photo","id":"1","photo_file_id":"AgAD","caption":"Cat"}"#;
+
+    match round_trip(json) {
+        InlineQueryResult::InlineQueryResultCachedPhoto(r) => {
+            assert_eq!(r.photo_file_id.as_str(), "AgAD");
+            assert_eq!(r.caption.as_deref(), Some("Cat"));
+        }
+        other => panic!("expected a cached photo, got {:?}", other),
+    }
+}
+
+#[test]
+fn photo_by_link() {
+    let json = r#"{"type":"photo","id":"1","photo_url":"https://example.com/cat.jpg","thumb_url":"https://example.com/thumb.jpg"}"#;
+
+    match round_trip(json) {
+        InlineQueryResult::InlineQueryResultPhoto(r) => {
+            assert_eq!(r.photo_url, "https://example.com/cat.jpg");
+        }
+        other => panic!("expected a photo by link, got {:?}", other),
+    }
+}
+
+#[test]
+fn cached_audio_and_audio_by_link() {
+    let cached = r#"{"type":"audio","id":"1","audio_file_id":"CQAD"}"#;
+    let link =
+        r#"{"type":"audio","id":"2","audio_url":"https://example.com/a.mp3","title":"Song"}"#;
+
+    match round_trip(cached) {
+        InlineQueryResult::InlineQueryResultCachedAudio(r) => {
+            assert_eq!(r.audio_file_id.as_str(), "CQAD")
+        }
+        other => panic!("expected a cached audio, got {:?}", other),
+    }
+    match round_trip(link) {
+        InlineQueryResult::InlineQueryResultAudio(r) => assert_eq!(r.title, "Song"),
+        other => panic!("expected an audio by link, got {:?}", other),
+    }
+}
+
+#[test]
+fn cached_only_and_link_only() {
+    let sticker = r#"{"type":"sticker","id":"1","sticker_file_id":"CAAD"}"#;
+    let article = r#"{"type":"article","id":"2","title":"News","input_message_content":{"message_text":"Hello"}}"#;
+
+    match round_trip(sticker) {
+        InlineQueryResult::InlineQueryResultCachedSticker(_) => {}
+        other => panic!("expected a sticker, got {:?}", other),
+    }
+    match round_trip(article) {
+        InlineQueryResult::InlineQueryResultArticle(r) => assert_eq!(r.title, "News"),
+        other => panic!("expected an article, got {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_type() {
+    let json = r#"{"type":"hologram","id":"1"}"#;
+
+    assert!(serde_json::from_str::<InlineQueryResult>(json).is_err());
+}
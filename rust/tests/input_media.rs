@@ -0,0 +1,74 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::{InputFile, InputMedia, ParseMode};
+
+fn round_trip(json: &str) -> InputMedia {
+    let media: InputMedia = serde_json::from_str(json).unwrap();
+    let value = serde_json::to_value(&media).unwrap();
+
+    assert_eq!(value, serde_json::from_str::<Value>(json).unwrap());
+
+    media
+}
+
+#[test]
+fn audio() {
+    let json = r#"{"type":"audio","media":"attach://song","thumb":"attach://cover","caption":"*Song*","parse_mode":"MarkdownV2","duration":215,"performer":"Band","title":"Song"}"#;
+
+    match round_trip(json) {
+        InputMedia::InputMediaAudio(m) => {
+            assert_eq!(*m.media, InputFile::Attach("song".to_owned()));
+            assert_eq!(
+                m.thumb,
+                Some(Box::new(InputFile::Attach("cover".to_owned())))
+            );
+            assert_eq!(m.parse_mode, Some(ParseMode::MarkdownV2));
+            assert_eq!(m.duration, Some(215));
+            assert_eq!(m.performer.as_deref(), Some("Band"));
+        }
+        other => panic!("expected an audio, got {:?}", other),
+    }
+}
+
+#[test]
+fn document() {
+    let json = r#"{"type":"document","media":"BQACAgIAAxkBAAIBZ2","caption":"Report","disable_content_type_detection":true}"#;
+
+    match round_trip(json) {
+        InputMedia::InputMediaDocument(m) => {
+            assert_eq!(*m.media, InputFile::FileId("BQACAgIAAxkBAAIBZ2".to_owned()));
+            assert_eq!(m.caption.as_deref(), Some("Report"));
+            assert_eq!(m.disable_content_type_detection, Some(true));
+        }
+        other => panic!("expected a document, got {:?}", other),
+    }
+}
+
+#[test]
+fn animation() {
+    let json = r#"{"type":"animation","media":"https://example.com/cat.gif","caption":"<b>Cat</b>","parse_mode":"HTML","width":320,"height":240,"duration":3}"#;
+
+    match round_trip(json) {
+        InputMedia::InputMediaAnimation(m) => {
+            assert_eq!(
+                *m.media,
+                InputFile::Url("https://example.com/cat.gif".to_owned())
+            );
+            assert_eq!(m.parse_mode, Some(ParseMode::Html));
+            assert_eq!(
+                (m.width, m.height, m.duration),
+                (Some(320), Some(240), Some(3))
+            );
+        }
+        other => panic!("expected an animation, got {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_type() {
+    let json = r#"{"type":"hologram","media":"attach://file"}"#;
+
+    assert!(serde_json::from_str::<InputMedia>(json).is_err());
+}
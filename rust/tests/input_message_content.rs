@@ -0,0 +1,83 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::InputMessageContent;
+
+fn round_trip(json: &str) -> InputMessageContent {
+    let content: InputMessageContent = serde_json::from_str(json).unwrap();
+    let value = serde_json::to_value(&content).unwrap();
+
+    assert!(value.get("type").is_none(), "union must not be tagged: {}", value);
+
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn text() {
+    let json = r#"{"message_text":"*Hello*","parse_mode":"Markdown","disable_web_page_preview":true}"#;
+
+    match round_trip(json) {
+        InputMessageContent::InputTextMessageContent(c) => {
+            assert_eq!(c.message_text, "*Hello*");
+            assert_eq!(c.disable_web_page_preview, Some(true));
+        }
+        other => panic!("expected text, got {:?}", other),
+    }
+}
+
+#[test]
+fn location() {
+    let json = r#"{"latitude":55.751244,"longitude":37.618423,"live_period":600}"#;
+
+    match round_trip(json) {
+        InputMessageContent::InputLocationMessageContent(c) => {
+            assert_eq!(c.latitude, 55.751244);
+            assert_eq!(c.live_period, Some(600));
+        }
+        other => panic!("expected location, got {:?}", other),
+    }
+}
+
+#[test]
+fn venue() {
+    let json = r#"{"latitude":55.753930,"longitude":37.620795,"title":"Red Square","address":"Moscow, Russia","foursquare_id":"4bd6e0f8e9c9c9b7f1a6e4a0"}"#;
+
+    match round_trip(json) {
+        InputMessageContent::InputVenueMessageContent(c) => {
+            assert_eq!(c.title, "Red Square");
+            assert_eq!(c.address, "Moscow, Russia");
+        }
+        other => panic!("expected venue, got {:?}", other),
+    }
+}
+
+#[test]
+fn contact() {
+    let json = r#"{"phone_number":"+79991234567","first_name":"Ivan","last_name":"Petrov"}"#;
+
+    match round_trip(json) {
+        InputMessageContent::InputContactMessageContent(c) => {
+            assert_eq!(c.phone_number, "+79991234567");
+            assert_eq!(c.last_name, Some("Petrov".to_owned()));
+        }
+        other => panic!("expected contact, got {:?}", other),
+    }
+}
+
+#[test]
+fn invoice() {
+    let json = r#"{"title":"Ticket","description":"One ride","payload":"ride-1","provider_token":"TOKEN","currency":"USD","prices":[{"label":"Ride","amount":150}]}"#;
+
+    match round_trip(json) {
+        InputMessageContent::InputInvoiceMessageContent(c) => {
+            assert_eq!(c.payload, "ride-1");
+            assert_eq!(c.prices[0].amount, 150);
+        }
+        other => panic!("expected invoice, got {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_shape_is_rejected() {
+    assert!(serde_json::from_str::<InputMessageContent>(r#"{"title":"nothing"}"#).is_err());
+}
@@ -0,0 +1,161 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{
+    InlineKeyboardButton, InlineKeyboardButtonAction, InlineKeyboardMarkup, KeyboardButton,
+    KeyboardError, ReplyKeyboardMarkup, INLINE_BUTTON_LIMIT, INLINE_ROW_LIMIT,
+};
+
+fn button(n: usize) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(n.to_string(), format!("item:{}", n))
+}
+
+#[test]
+fn rows_and_buttons() {
+    let markup = InlineKeyboardMarkup::builder()
+        .button(InlineKeyboardButton::callback("Yes", "yes"))
+        .button(InlineKeyboardButton::callback("No", "no"))
+        .row()
+        .row()
+        .button(InlineKeyboardButton::url("Help", "https://t.me"))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&markup).unwrap(),
+        serde_json::json!({"inline_keyboard": [
+            [{"text": "Yes", "callback_data": "yes"}, {"text": "No", "callback_data": "no"}],
+            [{"text": "Help", "url": "https://t.me"}],
+        ]})
+    );
+}
+
+#[test]
+fn grid() {
+    let markup = InlineKeyboardMarkup::builder()
+        .button(InlineKeyboardButton::callback("Back", "back"))
+        .grid((1..=7).map(button), 3)
+        .build()
+        .unwrap();
+    let lengths: Vec<_> = markup.inline_keyboard.iter().map(Vec::len).collect();
+
+    assert_eq!(lengths, [1, 3, 3, 1]);
+    assert_eq!(markup.inline_keyboard[3][0].text, "7");
+}
+
+fn pagination(page: usize, pages: usize) -> Vec<(String, String)> {
+    let markup = InlineKeyboardMarkup::builder()
+        .pagination(page, pages, |page| format!("page:{}", page))
+        .build()
+        .unwrap();
+
+    markup
+        .inline_keyboard
+        .iter()
+        .flatten()
+        .map(|button| match button.action {
+            InlineKeyboardButtonAction::Callback(ref data) => (button.text.clone(), data.clone()),
+            ref other => panic!("expected callback data, got {:?}", other),
+        })
+        .collect()
+}
+
+#[test]
+fn pagination_row() {
+    let texts = |page, pages| -> Vec<String> {
+        pagination(page, pages)
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect()
+    };
+
+    assert_eq!(texts(5, 10), ["« 1", "‹ 4", "· 5 ·", "6 ›", "10 »"]);
+    assert_eq!(texts(1, 10), ["· 1 ·", "2 ›", "10 »"]);
+    assert_eq!(texts(2, 3), ["‹ 1", "· 2 ·", "3 ›"]);
+    assert_eq!(texts(10, 10), ["« 1", "‹ 9", "· 10 ·"]);
+    assert!(texts(1, 1).is_empty());
+
+    assert_eq!(pagination(5, 10)[1].1, "page:4");
+}
+
+#[test]
+fn limits() {
+    let error = InlineKeyboardMarkup::builder()
+        .button(button(0))
+        .row()
+        .grid((0..INLINE_ROW_LIMIT + 1).map(button), INLINE_ROW_LIMIT + 1)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        KeyboardError::RowTooLong {
+            row: 1,
+            buttons: INLINE_ROW_LIMIT + 1,
+            limit: INLINE_ROW_LIMIT,
+        }
+    );
+
+    let error = InlineKeyboardMarkup::builder()
+        .grid((0..INLINE_BUTTON_LIMIT + 1).map(button), 5)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        KeyboardError::TooManyButtons {
+            buttons: INLINE_BUTTON_LIMIT + 1,
+            limit: INLINE_BUTTON_LIMIT,
+        }
+    );
+    assert!(InlineKeyboardMarkup::builder()
+        .grid((0..INLINE_BUTTON_LIMIT).map(button), INLINE_ROW_LIMIT)
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn grid_without_columns() {
+    let error = InlineKeyboardMarkup::builder()
+        .button(button(0))
+        .grid((1..=3).map(button), 0)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(error, KeyboardError::NoColumns);
+    assert_eq!(error.to_string(), "a grid needs at least one column");
+    assert_eq!(
+        ReplyKeyboardMarkup::builder()
+            .grid(vec!["1"], 0)
+            .build()
+            .unwrap_err(),
+        KeyboardError::NoColumns
+    );
+}
+
+#[test]
+fn reply_keyboard() {
+    let markup = ReplyKeyboardMarkup::builder()
+        .grid(vec!["1", "2", "3"], 2)
+        .row()
+        .button(KeyboardButton::request_contact("Share phone"))
+        .resize_keyboard(true)
+        .one_time_keyboard(true)
+        .selective(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&markup).unwrap(),
+        serde_json::json!({
+            "keyboard": [
+                [{"text": "1"}, {"text": "2"}],
+                [{"text": "3"}],
+                [{"text": "Share phone", "request_contact": true}],
+            ],
+            "resize_keyboard": true,
+            "one_time_keyboard": true,
+            "selective": true,
+        })
+    );
+}
@@ -0,0 +1,106 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::{MessageEntity, MessageEntityKind};
+
+fn round_trip(json: &str) -> MessageEntity {
+    let entity: MessageEntity = serde_json::from_str(json).unwrap();
+    let value = serde_json::to_value(&entity).unwrap();
+
+    assert_eq!(value, serde_json::from_str::<Value>(json).unwrap());
+
+    entity
+}
+
+#[test]
+fn known_kinds() {
+    let kinds = [
+        "mention",
+        "hashtag",
+        "cashtag",
+        "bot_command",
+        "url",
+        "email",
+        "phone_number",
+        "bold",
+        "italic",
+        "underline",
+        "strikethrough",
+        "code",
+        "pre",
+    ];
+
+    for kind in &kinds {
+        let json = format!(r#"{{"type":"{}","offset":1,"length":2}}"#, kind);
+        let entity = round_trip(&json);
+
+        assert_eq!(entity.kind.as_str(), *kind);
+        assert_eq!((entity.offset, entity.length), (1, 2));
+        if let MessageEntityKind::Unknown { .. } = entity.kind {
+            panic!("{} is a known kind", kind);
+        }
+    }
+}
+
+#[test]
+fn payloads() {
+    let entity =
+        round_trip(r#"{"type":"text_link","offset":0,"length":4,"url":"https://telegram.org"}"#);
+    match entity.kind {
+        MessageEntityKind::TextLink { ref url } => assert_eq!(url, "https://telegram.org"),
+        ref other => panic!("expected a text link, got {:?}", other),
+    }
+
+    let entity = round_trip(
+        r#"{"type":"text_mention","offset":0,"length":3,"user":{"id":1001,"is_bot":false,"first_name":"Ann"}}"#,
+    );
+    match entity.kind {
+        MessageEntityKind::TextMention { ref user } => assert_eq!(user.first_name, "Ann"),
+        ref other => panic!("expected a text mention, got {:?}", other),
+    }
+
+    let entity = round_trip(r#"{"type":"pre","offset":0,"length":9,"language":"rust"}"#);
+    match entity.kind {
+        MessageEntityKind::Pre { ref language } => {
+            assert_eq!(language.as_deref(), Some("rust"))
+        }
+        ref other => panic!("expected a pre, got {:?}", other),
+    }
+
+    let missing = r#"{"type":"text_link","offset":0,"length":4}"#;
+    assert!(serde_json::from_str::<MessageEntity>(missing).is_err());
+}
+
+#[test]
+fn unknown_kind() {
+    let json =
+        r#"{"type":"custom_emoji","offset":0,"length":2,"custom_emoji_id":"5368324170671202286"}"#;
+    let entity = round_trip(json);
+
+    match entity.kind {
+        MessageEntityKind::Unknown {
+            ref type_tl,
+            ref fields,
+        } => {
+            assert_eq!(type_tl, "custom_emoji");
+            assert_eq!(fields["custom_emoji_id"], "5368324170671202286");
+            assert_eq!(fields.len(), 1);
+        }
+        ref other => panic!("expected an unknown kind, got {:?}", other),
+    }
+    assert_eq!(entity.kind.as_str(), "custom_emoji");
+
+    // Written once, not by both the kind and the `extra` map
+    let text = serde_json::to_string(&entity).unwrap();
+    assert_eq!(text.matches("custom_emoji_id").count(), 1);
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn unknown_fields_of_known_kinds() {
+    let entity = round_trip(r#"{"type":"bold","offset":0,"length":2,"depth":1}"#);
+
+    assert_eq!(entity.extra["depth"], 1);
+    assert_eq!(entity.extra.len(), 1);
+}
@@ -0,0 +1,109 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::{ChatId, Message, MessageKind};
+
+fn parse(content: Value) -> Message {
+    let mut json = serde_json::json!({
+        "message_id": 1,
+        "chat": {"id": -100, "type": "supergroup", "title": "Chat"},
+        "date": 0,
+    });
+    for (key, value) in content.as_object().unwrap() {
+        json[key] = value.clone();
+    }
+
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn text() {
+    let message = parse(serde_json::json!({
+        "text": "/start",
+        "entities": [{"type": "bot_command", "offset": 0, "length": 6}],
+    }));
+
+    match message.kind() {
+        MessageKind::Text { text, entities } => {
+            assert_eq!(text, "/start");
+            assert_eq!(entities.len(), 1);
+        }
+        other => panic!("expected a text, got {:?}", other),
+    }
+    assert!(!message.kind().is_service());
+}
+
+#[test]
+fn photo() {
+    let message = parse(serde_json::json!({
+        "photo": [{"file_id": "AgAD", "file_unique_id": "AQAD", "width": 90, "height": 90}],
+        "caption": "Cat",
+        "caption_entities": [{"type": "bold", "offset": 0, "length": 3}],
+    }));
+
+    match message.kind() {
+        MessageKind::Photo {
+            photo,
+            caption,
+            caption_entities,
+        } => {
+            assert_eq!(photo[0].width, 90);
+            assert_eq!(caption, Some("Cat"));
+            assert_eq!(caption_entities.len(), 1);
+        }
+        other => panic!("expected a photo, got {:?}", other),
+    }
+
+    let message = parse(serde_json::json!({
+        "photo": [{"file_id": "AgAD", "file_unique_id": "AQAD", "width": 90, "height": 90}],
+    }));
+    match message.kind() {
+        MessageKind::Photo {
+            caption,
+            caption_entities,
+            ..
+        } => {
+            assert_eq!(caption, None);
+            assert!(caption_entities.is_empty());
+        }
+        other => panic!("expected a photo, got {:?}", other),
+    }
+}
+
+#[test]
+fn service_messages() {
+    let message = parse(serde_json::json!({
+        "new_chat_members": [
+            {"id": 1001, "is_bot": false, "first_name": "Ann"},
+            {"id": 1002, "is_bot": true, "first_name": "Bot"},
+        ],
+    }));
+
+    match message.kind() {
+        MessageKind::NewChatMembers(users) => {
+            let names: Vec<_> = users.iter().map(|user| &*user.first_name).collect();
+            assert_eq!(names, ["Ann", "Bot"]);
+        }
+        other => panic!("expected new members, got {:?}", other),
+    }
+    assert!(message.kind().is_service());
+
+    let message = parse(serde_json::json!({"migrate_to_chat_id": -1001234567890i64}));
+    match message.kind() {
+        MessageKind::MigrateToChatId(id) => assert_eq!(id, ChatId(-1001234567890)),
+        other => panic!("expected a migration, got {:?}", other),
+    }
+    assert!(message.kind().is_service());
+}
+
+#[test]
+fn unknown_content() {
+    let message = parse(serde_json::json!({"story": {"id": 1}}));
+
+    match message.kind() {
+        MessageKind::Unknown => {}
+        other => panic!("expected unknown content, got {:?}", other),
+    }
+    assert!(!message.kind().is_service());
+}
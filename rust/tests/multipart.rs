@@ -0,0 +1,283 @@
+#![cfg(feature = "multipart")]
+
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{
+    attach_media, EncodeError, InputFile, InputMedia, Multipart, ParseMode, Part, ReplyMarkup,
+    RequestBody, SendMediaGroup, SendPhoto,
+};
+
+const BOUNDARY: &str = "xXxBoundaryxXx";
+
+fn send_photo(photo: InputFile) -> SendPhoto {
+    SendPhoto {
+        chat_id: 42.into(),
+        photo: Box::new(photo),
+        caption: Some("A \"cat\"".to_owned()),
+        parse_mode: Some(ParseMode::Html),
+        caption_entities: None,
+        disable_notification: Some(true),
+        reply_to_message_id: None,
+        allow_sending_without_reply: None,
+        reply_markup: Some(Box::new(
+            serde_json::from_str::<ReplyMarkup>(r#"{"force_reply":true}"#).unwrap(),
+        )),
+    }
+}
+
+#[test]
+fn json_when_nothing_is_uploaded() {
+    let request = send_photo(InputFile::file_id("AgADBAADv6cxG"));
+
+    match RequestBody::with_boundary(&request, BOUNDARY).unwrap() {
+        RequestBody::Json(bytes) => {
+            assert_eq!(bytes, serde_json::to_vec(&request).unwrap());
+        }
+        other => panic!("expected JSON, got {:?}", other),
+    }
+}
+
+#[test]
+fn multipart_when_a_file_is_uploaded() {
+    let request = send_photo(InputFile::upload("cat.jpg", &b"\xFF\xD8\xFF"[..]));
+    let body = RequestBody::with_boundary(&request, BOUNDARY).unwrap();
+
+    assert_eq!(
+        body.content_type(),
+        "multipart/form-data; boundary=xXxBoundaryxXx"
+    );
+
+    let expected: &[u8] = b"--xXxBoundaryxXx\r\n\
+          Content-Disposition: form-data; name=\"chat_id\"\r\n\
+          \r\n\
+          42\r\n\
+          --xXxBoundaryxXx\r\n\
+          Content-Disposition: form-data; name=\"photo\"; filename=\"cat.jpg\"\r\n\
+          Content-Type: application/octet-stream\r\n\
+          \r\n\
+          \xFF\xD8\xFF\r\n\
+          --xXxBoundaryxXx\r\n\
+          Content-Disposition: form-data; name=\"caption\"\r\n\
+          \r\n\
+          A \"cat\"\r\n\
+          --xXxBoundaryxXx\r\n\
+          Content-Disposition: form-data; name=\"parse_mode\"\r\n\
+          \r\n\
+          HTML\r\n\
+          --xXxBoundaryxXx\r\n\
+          Content-Disposition: form-data; name=\"disable_notification\"\r\n\
+          \r\n\
+          true\r\n\
+          --xXxBoundaryxXx\r\n\
+          Content-Disposition: form-data; name=\"reply_markup\"\r\n\
+          \r\n\
+          {\"force_reply\":true}\r\n\
+          --xXxBoundaryxXx--\r\n";
+
+    assert_eq!(body.into_bytes(), expected);
+}
+
+#[test]
+fn upload_parts() {
+    let request = send_photo(InputFile::Upload {
+        filename: "my \"cat\".png".to_owned(),
+        data: vec![1, 2, 3],
+        mime_type: Some("image/png".to_owned()),
+    });
+
+    let form = match RequestBody::with_boundary(&request, BOUNDARY).unwrap() {
+        RequestBody::Multipart(form) => form,
+        other => panic!("expected multipart, got {:?}", other),
+    };
+
+    assert_eq!(form.boundary(), BOUNDARY);
+    assert_eq!(
+        form.parts()[1],
+        Part::file(
+            "photo",
+            "my \"cat\".png",
+            vec![1, 2, 3],
+            Some("image/png".to_owned())
+        )
+    );
+
+    let bytes = String::from_utf8(form.to_bytes()).unwrap();
+
+    assert!(bytes.contains(
+        "name=\"photo\"; filename=\"my %22cat%22.png\"\r\nContent-Type: image/png\r\n\r\n"
+    ));
+}
+
+#[test]
+fn mime_types_that_break_the_header_are_rejected() {
+    for mime_type in &["image/png\r\nX-Evil: 1", "image/\"png\""] {
+        let request = send_photo(InputFile::Upload {
+            filename: "cat.png".to_owned(),
+            data: vec![1, 2, 3],
+            mime_type: Some((*mime_type).to_owned()),
+        });
+
+        match RequestBody::with_boundary(&request, BOUNDARY) {
+            Err(EncodeError::InvalidMimeType(ref value)) if value == mime_type => (),
+            other => panic!("expected an error, got {:?}", other),
+        }
+    }
+
+    let mut form = Multipart::with_boundary(BOUNDARY);
+    form.push(Part::file(
+        "photo",
+        "cat.png",
+        vec![1],
+        Some("image/png\r\nX-Evil: 1".to_owned()),
+    ));
+    let bytes = String::from_utf8(form.to_bytes()).unwrap();
+
+    assert!(bytes.contains("Content-Type: image/png%0D%0AX-Evil: 1\r\n\r\n"));
+}
+
+#[test]
+fn hand_built_form() {
+    let mut form = Multipart::with_boundary("b");
+    form.push(Part::text("chat_id", "1"));
+    form.push(Part::file("document", "a.txt", &b"hi"[..], None));
+
+    assert_eq!(
+        form.to_bytes(),
+        &b"--b\r\n\
+           Content-Disposition: form-data; name=\"chat_id\"\r\n\
+           \r\n\
+           1\r\n\
+           --b\r\n\
+           Content-Disposition: form-data; name=\"document\"; filename=\"a.txt\"\r\n\
+           Content-Type: application/octet-stream\r\n\
+           \r\n\
+           hi\r\n\
+           --b--\r\n"[..]
+    );
+}
+
+#[test]
+fn random_boundaries_differ() {
+    assert_ne!(Multipart::new().boundary(), Multipart::new().boundary());
+}
+
+#[test]
+fn only_objects_can_be_encoded() {
+    match RequestBody::new(&42) {
+        Err(EncodeError::NotAnObject) => (),
+        other => panic!("expected an error, got {:?}", other),
+    }
+}
+
+#[test]
+fn nested_uploads_are_rejected() {
+    let mut map = std::collections::BTreeMap::new();
+    map.insert("media", vec![InputFile::upload("a.png", vec![0])]);
+
+    match RequestBody::new(&map) {
+        Err(EncodeError::Json(_)) => (),
+        other => panic!("expected an error, got {:?}", other),
+    }
+}
+
+fn media(json: &str, file: InputFile, thumb: Option<InputFile>) -> Box<InputMedia> {
+    let mut media: InputMedia = serde_json::from_str(json).unwrap();
+
+    match media {
+        InputMedia::InputMediaPhoto(ref mut m) => *m.media = file,
+        InputMedia::InputMediaVideo(ref mut m) => {
+            *m.media = file;
+            m.thumb = thumb.map(Box::new);
+        }
+        _ => unreachable!(),
+    }
+
+    Box::new(media)
+}
+
+#[test]
+fn album_uploads_are_attached() {
+    let cat = InputFile::upload("cat.jpg", vec![1, 2, 3]);
+    let mut album = vec![
+        media(r#"{"type":"photo","media":"x"}"#, cat.clone(), None),
+        media(
+            r#"{"type":"video","media":"x"}"#,
+            InputFile::upload("cat.mp4", vec![4, 5]),
+            Some(cat.clone()),
+        ),
+        media(
+            r#"{"type":"photo","media":"x"}"#,
+            InputFile::file_id("AgAD"),
+            None,
+        ),
+    ];
+
+    let parts = attach_media(&mut album);
+
+    assert_eq!(
+        parts,
+        vec![
+            Part::file("file0", "cat.jpg", vec![1, 2, 3], None),
+            Part::file("file1", "cat.mp4", vec![4, 5], None),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_value(&album).unwrap(),
+        serde_json::json!([
+            {"type": "photo", "media": "attach://file0"},
+            {"type": "video", "media": "attach://file1", "thumb": "attach://file0"},
+            {"type": "photo", "media": "AgAD"},
+        ])
+    );
+
+    let request = SendMediaGroup {
+        chat_id: 42.into(),
+        media: album,
+        disable_notification: None,
+        reply_to_message_id: None,
+        allow_sending_without_reply: None,
+    };
+
+    let form = match RequestBody::with_attachments(&request, parts).unwrap() {
+        RequestBody::Multipart(form) => form,
+        other => panic!("expected multipart, got {:?}", other),
+    };
+    let names: Vec<_> = form.parts().iter().map(|p| p.name.as_str()).collect();
+
+    assert_eq!(names, ["chat_id", "media", "file0", "file1"]);
+}
+
+#[test]
+fn attach_names_of_the_caller_are_skipped() {
+    let mut album = vec![
+        media(
+            r#"{"type":"photo","media":"x"}"#,
+            InputFile::upload("cat.jpg", vec![1]),
+            None,
+        ),
+        media(
+            r#"{"type":"video","media":"x"}"#,
+            InputFile::attach("file0"),
+            Some(InputFile::attach("file2")),
+        ),
+        media(
+            r#"{"type":"photo","media":"x"}"#,
+            InputFile::upload("dog.jpg", vec![2]),
+            None,
+        ),
+    ];
+
+    let parts = attach_media(&mut album);
+    let names: Vec<_> = parts.iter().map(|p| p.name.as_str()).collect();
+
+    assert_eq!(names, ["file1", "file3"]);
+    assert_eq!(
+        serde_json::to_value(&album).unwrap(),
+        serde_json::json!([
+            {"type": "photo", "media": "attach://file1"},
+            {"type": "video", "media": "attach://file0", "thumb": "attach://file2"},
+            {"type": "photo", "media": "attach://file3"},
+        ])
+    );
+}
@@ -0,0 +1,47 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{ChatId, ParseMode, SendMessage, SendPoll};
+
+#[test]
+fn wire_names() {
+    let modes = [
+        (ParseMode::Markdown, "Markdown"),
+        (ParseMode::MarkdownV2, "MarkdownV2"),
+        (ParseMode::Html, "HTML"),
+    ];
+
+    for &(mode, name) in &modes {
+        let json = format!("\"{}\"", name);
+
+        assert_eq!(serde_json::to_string(&mode).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ParseMode>(&json).unwrap(), mode);
+        assert_eq!(mode.as_str(), name);
+        assert_eq!(mode.to_string(), name);
+    }
+
+    assert!(serde_json::from_str::<ParseMode>("\"html\"").is_err());
+}
+
+#[test]
+fn method_fields() {
+    let message = SendMessage {
+        chat_id: ChatId(1).into(),
+        text: "<b>Hi</b>".to_owned(),
+        parse_mode: Some(ParseMode::Html),
+        entities: None,
+        disable_web_page_preview: None,
+        disable_notification: None,
+        reply_to_message_id: None,
+        allow_sending_without_reply: None,
+        reply_markup: None,
+    };
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["parse_mode"], "HTML");
+
+    let poll: SendPoll = serde_json::from_str(
+        r#"{"chat_id":1,"question":"?","options":["Yes","No"],"explanation":"*Yes*","explanation_parse_mode":"MarkdownV2"}"#,
+    )
+    .unwrap();
+    assert_eq!(poll.explanation_parse_mode, Some(ParseMode::MarkdownV2));
+}
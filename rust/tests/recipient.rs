@@ -0,0 +1,60 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{ChatId, GetChat, Recipient};
+
+#[test]
+fn serialized_as_number_or_username() {
+    let by_id = GetChat {
+        chat_id: Recipient::from(-1001234567890),
+    };
+    let by_name = GetChat {
+        chat_id: Recipient::username("@telegram").unwrap(),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&by_id).unwrap(),
+        r#"{"chat_id":-1001234567890}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&by_name).unwrap(),
+        r#"{"chat_id":"@telegram"}"#
+    );
+}
+
+#[test]
+fn deserialized_from_either_form() {
+    let parse = |json: &str| serde_json::from_str::<Recipient>(json);
+
+    assert_eq!(parse("42").unwrap(), Recipient::Id(ChatId(42)));
+    assert_eq!(parse(r#""-42""#).unwrap(), Recipient::Id(ChatId(-42)));
+    assert_eq!(
+        parse(r#""@durov_bot""#).unwrap(),
+        Recipient::Username("durov_bot".to_owned())
+    );
+    assert!(parse(r#""durov""#).is_err());
+    assert!(parse(r#""@du""#).is_err());
+    assert!(parse("true").is_err());
+}
+
+#[test]
+fn usernames_are_validated() {
+    assert_eq!(
+        Recipient::username("telegram").unwrap().to_string(),
+        "@telegram"
+    );
+
+    for invalid in &[
+        "",
+        "@",
+        "@abcd",
+        "@1channel",
+        "@_channel",
+        "@channel_",
+        "@chan nel",
+        "@канал_новостей",
+        "@a_very_long_channel_name_over_32_chars",
+    ] {
+        assert!(Recipient::username(invalid).is_err(), "{}", invalid);
+    }
+}
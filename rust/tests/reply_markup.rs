@@ -0,0 +1,88 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use telegram_typings::{InlineKeyboardButton, InlineKeyboardMarkup, ReplyMarkup};
+
+fn parse(json: &str) -> Result<ReplyMarkup, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn picked_by_key() {
+    match parse(r#"{"inline_keyboard":[[{"text":"A","callback_data":"a"}]]}"#).unwrap() {
+        ReplyMarkup::InlineKeyboardMarkup(m) => assert_eq!(m.inline_keyboard[0][0].text, "A"),
+        other => panic!("expected an inline keyboard, got {:?}", other),
+    }
+    match parse(r#"{"keyboard":[[{"text":"A"}]],"resize_keyboard":true}"#).unwrap() {
+        ReplyMarkup::ReplyKeyboardMarkup(m) => assert_eq!(m.resize_keyboard, Some(true)),
+        other => panic!("expected a reply keyboard, got {:?}", other),
+    }
+    match parse(r#"{"remove_keyboard":true,"selective":true}"#).unwrap() {
+        ReplyMarkup::ReplyKeyboardRemove(m) => assert_eq!(m.selective, Some(true)),
+        other => panic!("expected a keyboard removal, got {:?}", other),
+    }
+    match parse(r#"{"force_reply":true,"input_field_placeholder":"Name"}"#).unwrap() {
+        ReplyMarkup::ForceReply(m) => {
+            assert_eq!(m.input_field_placeholder.as_deref(), Some("Name"))
+        }
+        other => panic!("expected a forced reply, got {:?}", other),
+    }
+}
+
+#[test]
+fn ambiguous_or_unknown() {
+    let error = parse(r#"{"keyboard":[],"remove_keyboard":true}"#).unwrap_err();
+    assert!(error.to_string().contains("more than one"));
+
+    let error = parse(r#"{"selective":true}"#).unwrap_err();
+    assert!(error.to_string().contains("none of"));
+}
+
+#[test]
+fn serialized_untagged() {
+    let markup: ReplyMarkup = InlineKeyboardMarkup::builder()
+        .button(InlineKeyboardButton::callback("A", "a"))
+        .build()
+        .unwrap()
+        .into();
+
+    assert_eq!(
+        serde_json::to_string(&markup).unwrap(),
+        r#"{"inline_keyboard":[[{"text":"A","callback_data":"a"}]]}"#
+    );
+
+    let json = r#"{"keyboard":[[{"text":"A"}]],"one_time_keyboard":true}"#;
+    assert_eq!(serde_json::to_string(&parse(json).unwrap()).unwrap(), json);
+}
+
+#[test]
+fn all_fields_reach_the_object() {
+    let json = r#"{"keyboard":[[{"text":"A","request_contact":true}]],"selective":true,"input_field_placeholder":"Pick"}"#;
+
+    match parse(json).unwrap() {
+        ReplyMarkup::ReplyKeyboardMarkup(m) => {
+            assert_eq!(m.keyboard[0][0].request_contact, Some(true));
+            assert_eq!(m.selective, Some(true));
+            assert_eq!(m.input_field_placeholder.as_deref(), Some("Pick"));
+        }
+        other => panic!("expected a reply keyboard, got {:?}", other),
+    }
+
+    let error = parse(r#"{"force_reply":"yes"}"#).unwrap_err();
+    assert!(error.to_string().contains("invalid type"));
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn unknown_fields_are_kept() {
+    let json = r#"{"is_persistent":true,"keyboard":[[{"text":"A"}]]}"#;
+
+    match parse(json).unwrap() {
+        ReplyMarkup::ReplyKeyboardMarkup(ref m) => assert_eq!(m.extra["is_persistent"], true),
+        ref other => panic!("expected a reply keyboard, got {:?}", other),
+    }
+    assert_eq!(
+        serde_json::to_value(parse(json).unwrap()).unwrap(),
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+}
@@ -0,0 +1,140 @@
+//! lib.rs is generated from the same schema as the TypeScript typings, so
+//! check that both describe the same set of types. The generated code can
+//! only be compared with the committed one with access to the Bot API docs,
+//! see `npm run check:rust`.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Phrases the Bot API docs open a method description with
+const METHOD_DOCS: &[&str] = &["Use this method", "A simple method", "Informs a user"];
+
+fn read(path: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Names of the exported interfaces and type aliases, split into types and
+/// methods
+fn typescript_exports() -> (BTreeSet<String>, BTreeSet<String>) {
+    let source = read("../javascript/index.d.ts");
+    let mut types = BTreeSet::new();
+    let mut methods = BTreeSet::new();
+    let mut doc = String::new();
+
+    for line in source.lines() {
+        let line = line.trim();
+
+        if line.starts_with("/**") {
+            doc.clear();
+        }
+        if line.starts_with('*') || line.starts_with("/**") {
+            doc.push_str(line);
+            doc.push(' ');
+            continue;
+        }
+
+        let name = line
+            .strip_prefix("export interface ")
+            .or_else(|| line.strip_prefix("export type "));
+
+        if let Some(name) = name {
+            let name = name
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap();
+
+            if METHOD_DOCS.iter().any(|phrase| doc.contains(phrase)) {
+                methods.insert(name.to_owned());
+            } else {
+                types.insert(name.to_owned());
+            }
+            doc.clear();
+        }
+    }
+
+    (types, methods)
+}
+
+/// Modules with types of the schema written by hand rather than generated
+/// into lib.rs
+const HAND_WRITTEN: &[&str] = &["src/input_file.rs"];
+
+/// Names that follow `prefix` at the start of a line of the code
+fn items_in(path: &str, prefix: &str) -> BTreeSet<String> {
+    read(path)
+        .lines()
+        .filter_map(|line| line.strip_prefix(prefix))
+        .map(|name| {
+            name.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap()
+                .to_owned()
+        })
+        .collect()
+}
+
+/// Names that follow `prefix` at the start of a line of the generated code
+fn rust_items(prefix: &str) -> BTreeSet<String> {
+    items_in("src/lib.rs", prefix)
+}
+
+#[test]
+fn rust_types_match_typescript() {
+    let (types, methods) = typescript_exports();
+    let typescript: BTreeSet<_> = types.union(&methods).cloned().collect();
+    let mut rust: BTreeSet<_> = rust_items("pub struct ")
+        .union(&rust_items("pub enum "))
+        .cloned()
+        .collect();
+    for path in HAND_WRITTEN {
+        rust.extend(items_in(path, "pub enum "));
+    }
+
+    let missing: Vec<_> = typescript.difference(&rust).collect();
+    let unexpected: Vec<_> = rust.difference(&typescript).collect();
+
+    assert!(
+        missing.is_empty() && unexpected.is_empty(),
+        "missing in Rust: {:?}, not in TypeScript: {:?}",
+        missing,
+        unexpected
+    );
+}
+
+#[test]
+fn every_method_implements_method() {
+    let (_, methods) = typescript_exports();
+    let implemented = rust_items("impl Method for ");
+
+    assert_eq!(methods, implemented);
+}
+
+#[test]
+fn typescript_exports_are_found() {
+    let (types, methods) = typescript_exports();
+
+    for name in &["Update", "Message", "Poll", "ChatMember", "BotCommandScope"] {
+        assert!(types.contains(*name), "type {} not found", name);
+    }
+    for name in &["GetUpdates", "SendMessage", "SetPassportDataErrors"] {
+        assert!(methods.contains(*name), "method {} not found", name);
+    }
+}
+
+#[test]
+fn generator_prelude_matches() {
+    let builder = read("../lib/builders/rust.js");
+    let start = builder
+        .find("const PRELUDE = `")
+        .expect("no PRELUDE in rust.js")
+        + 17;
+    let prelude = &builder[start..];
+    let prelude = prelude[..prelude.find("`\n").unwrap()].replace("\\`", "`");
+
+    assert!(
+        read("src/lib.rs").starts_with(&prelude),
+        "the PRELUDE of lib/builders/rust.js differs from the start of src/lib.rs"
+    );
+}
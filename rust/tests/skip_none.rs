@@ -0,0 +1,64 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::{ChatId, Message, SendMessage, User};
+
+fn has_null(value: &Value) -> bool {
+    match *value {
+        Value::Null => true,
+        Value::Array(ref items) => items.iter().any(has_null),
+        Value::Object(ref fields) => fields.values().any(has_null),
+        _ => false,
+    }
+}
+
+#[test]
+fn object() {
+    let json = r#"{"id":1001,"is_bot":false,"first_name":"Ann"}"#;
+    let user: User = serde_json::from_str(json).unwrap();
+
+    assert_eq!(user.last_name, None);
+    assert_eq!(serde_json::to_string(&user).unwrap(), json);
+}
+
+#[test]
+fn nested_objects() {
+    let message: Message = serde_json::from_str(
+        r#"{
+            "message_id": 7,
+            "from": {"id": 1001, "is_bot": false, "first_name": "Ann"},
+            "chat": {"id": 1001, "type": "private"},
+            "date": 0,
+            "reply_to_message": {"message_id": 6, "chat": {"id": 1001, "type": "private"}, "date": 0},
+            "photo": [{"file_id": "AgAD", "file_unique_id": "AQAD", "width": 90, "height": 90}]
+        }"#,
+    )
+    .unwrap();
+    let value = serde_json::to_value(&message).unwrap();
+
+    assert!(!has_null(&value), "null in {}", value);
+    assert!(value.get("text").is_none());
+    assert!(value["reply_to_message"].get("from").is_none());
+    assert!(value["photo"][0].get("file_size").is_none());
+}
+
+#[test]
+fn method() {
+    let message = SendMessage {
+        chat_id: ChatId(1).into(),
+        text: "Hi".to_owned(),
+        parse_mode: None,
+        entities: None,
+        disable_web_page_preview: None,
+        disable_notification: None,
+        reply_to_message_id: None,
+        allow_sending_without_reply: None,
+        reply_markup: None,
+    };
+
+    assert_eq!(
+        serde_json::to_string(&message).unwrap(),
+        r#"{"chat_id":1,"text":"Hi"}"#
+    );
+}
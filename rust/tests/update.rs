@@ -0,0 +1,159 @@
+extern crate serde_json;
+extern crate telegram_typings;
+
+use serde_json::Value;
+use telegram_typings::{Update, UpdateKind};
+
+const USER: &str = r#"{"id": 1001, "is_bot": false, "first_name": "Ann"}"#;
+
+fn payloads() -> Vec<(&'static str, String)> {
+    let message = format!(
+        r#"{{"message_id": 7, "from": {}, "chat": {{"id": 1001, "type": "private"}}, "date": 0, "text": "Hi"}}"#,
+        USER
+    );
+    let member = format!(r#"{{"status": "member", "user": {}}}"#, USER);
+
+    vec![
+        ("message", message.clone()),
+        ("edited_message", message.clone()),
+        ("channel_post", message.clone()),
+        ("edited_channel_post", message),
+        (
+            "inline_query",
+            format!(
+                r#"{{"id": "1", "from": {}, "query": "cats", "offset": ""}}"#,
+                USER
+            ),
+        ),
+        (
+            "chosen_inline_result",
+            format!(r#"{{"result_id": "1", "from": {}, "query": "cats"}}"#, USER),
+        ),
+        (
+            "callback_query",
+            format!(r#"{{"id": "1", "from": {}, "chat_instance": "-1"}}"#, USER),
+        ),
+        (
+            "shipping_query",
+            format!(
+                r#"{{"id": "1", "from": {}, "invoice_payload": "p", "shipping_address": {{"country_code": "UA", "state": "", "city": "Kyiv", "street_line1": "Main", "street_line2": "", "post_code": "01001"}}}}"#,
+                USER
+            ),
+        ),
+        (
+            "pre_checkout_query",
+            format!(
+                r#"{{"id": "1", "from": {}, "currency": "USD", "total_amount": 150, "invoice_payload": "p"}}"#,
+                USER
+            ),
+        ),
+        (
+            "poll",
+            r#"{"id": "1", "question": "?", "options": [{"text": "Yes", "voter_count": 1}], "total_voter_count": 1, "is_closed": true, "is_anonymous": true, "type": "regular", "allows_multiple_answers": false}"#.to_owned(),
+        ),
+        (
+            "poll_answer",
+            format!(r#"{{"poll_id": "1", "user": {}, "option_ids": [0]}}"#, USER),
+        ),
+        (
+            "my_chat_member",
+            format!(
+                r#"{{"chat": {{"id": 1001, "type": "private"}}, "from": {}, "date": 0, "old_chat_member": {}, "new_chat_member": {}}}"#,
+                USER, member, member
+            ),
+        ),
+        (
+            "chat_member",
+            format!(
+                r#"{{"chat": {{"id": -1, "type": "group", "title": "G"}}, "from": {}, "date": 0, "old_chat_member": {}, "new_chat_member": {}}}"#,
+                USER, member, member
+            ),
+        ),
+    ]
+}
+
+fn field(kind: &UpdateKind) -> &'static str {
+    match *kind {
+        UpdateKind::Message(_) => "message",
+        UpdateKind::EditedMessage(_) => "edited_message",
+        UpdateKind::ChannelPost(_) => "channel_post",
+        UpdateKind::EditedChannelPost(_) => "edited_channel_post",
+        UpdateKind::InlineQuery(_) => "inline_query",
+        UpdateKind::ChosenInlineResult(_) => "chosen_inline_result",
+        UpdateKind::CallbackQuery(_) => "callback_query",
+        UpdateKind::ShippingQuery(_) => "shipping_query",
+        UpdateKind::PreCheckoutQuery(_) => "pre_checkout_query",
+        UpdateKind::Poll(_) => "poll",
+        UpdateKind::PollAnswer(_) => "poll_answer",
+        UpdateKind::MyChatMember(_) => "my_chat_member",
+        UpdateKind::ChatMember(_) => "chat_member",
+        UpdateKind::Unknown(_) => "unknown",
+    }
+}
+
+#[test]
+fn known_kinds() {
+    for (name, payload) in payloads() {
+        let json = format!(r#"{{"update_id": 1, "{}": {}}}"#, name, payload);
+
+        let update: Update = serde_json::from_str(&json).unwrap();
+        let from_update = update.into_kind();
+        assert_eq!(field(&from_update), name);
+
+        let direct: UpdateKind = serde_json::from_str(&json).unwrap();
+        assert_eq!(field(&direct), name);
+
+        let value = serde_json::to_value(&direct).unwrap();
+        assert_eq!(value, serde_json::to_value(&from_update).unwrap());
+        assert_eq!(value.as_object().unwrap().len(), 1);
+        assert!(value[name].is_object(), "{} serialized as {}", name, value);
+    }
+}
+
+#[test]
+fn unknown_kind() {
+    let json = r#"{"update_id": 1, "message_reaction": {"chat": {"id": 1, "type": "private"}}}"#;
+
+    let direct: UpdateKind = serde_json::from_str(json).unwrap();
+    match direct {
+        UpdateKind::Unknown(ref name) => assert_eq!(name.as_deref(), Some("message_reaction")),
+        ref other => panic!("expected an unknown update, got {:?}", other),
+    }
+
+    let value = serde_json::to_value(&direct).unwrap();
+    assert_eq!(value, serde_json::json!({ "message_reaction": null }));
+    match serde_json::from_value(value).unwrap() {
+        UpdateKind::Unknown(name) => assert_eq!(name.as_deref(), Some("message_reaction")),
+        other => panic!("expected an unknown update, got {:?}", other),
+    }
+
+    let update: Update = serde_json::from_str(json).unwrap();
+    match update.into_kind() {
+        #[cfg(feature = "extra-fields")]
+        UpdateKind::Unknown(name) => assert_eq!(name.as_deref(), Some("message_reaction")),
+        #[cfg(not(feature = "extra-fields"))]
+        UpdateKind::Unknown(name) => assert_eq!(name, None),
+        other => panic!("expected an unknown update, got {:?}", other),
+    }
+}
+
+#[test]
+fn no_payload() {
+    let json = r#"{"update_id": 1}"#;
+
+    let direct: UpdateKind = serde_json::from_str(json).unwrap();
+    match direct {
+        UpdateKind::Unknown(None) => {}
+        ref other => panic!("expected an unknown update, got {:?}", other),
+    }
+    assert_eq!(
+        serde_json::to_value(&direct).unwrap(),
+        Value::Object(Default::default())
+    );
+
+    let update: Update = serde_json::from_str(json).unwrap();
+    match update.into_kind() {
+        UpdateKind::Unknown(None) => {}
+        other => panic!("expected an unknown update, got {:?}", other),
+    }
+}